@@ -1,6 +1,8 @@
 /// Border characters used by components such as [`crate::borderize::Borderize`].
 ///
-/// This is a simplified border definition (corners + sides).
+/// Besides corners and sides it carries the junction characters used where inner lines
+/// meet, so grid-like components can reuse the same style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Border {
     /// Horizontal top line character.
     pub top: &'static str,
@@ -18,134 +20,79 @@ pub struct Border {
     pub bottom_left: &'static str,
     /// Bottom-right corner character.
     pub bottom_right: &'static str,
+    /// Junction of the left side and an inner horizontal line.
+    pub middle_left: &'static str,
+    /// Junction of the right side and an inner horizontal line.
+    pub middle_right: &'static str,
+    /// Crossing of inner horizontal and vertical lines.
+    pub middle: &'static str,
+    /// Junction of the top side and an inner vertical line.
+    pub middle_top: &'static str,
+    /// Junction of the bottom side and an inner vertical line.
+    pub middle_bottom: &'static str,
 }
 
-impl Default for Border {
-    fn default() -> Self {
-        Self {
-            top: "─",
-            bottom: "─",
-            left: "│",
-            right: "│",
-            top_left: "╭",
-            top_right: "╮",
-            bottom_left: "╰",
-            bottom_right: "╯",
-        }
-    }
+/// Predefined border styles for [`Border::style`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// Rounded corners, e.g. `╭─╮`.
+    #[default]
+    Rounded,
+    /// Square corners, e.g. `┌─┐`.
+    Normal,
+    /// Heavy lines, e.g. `┏━┓`.
+    Thick,
+    /// Double lines, e.g. `╔═╗`.
+    Double,
+    /// Full blocks, e.g. `███`.
+    Block,
+    /// Spaces, which keep the layout of a border without drawing it.
+    Hidden,
 }
 
-/*
-          Top:          "─",
-        Bottom:       "─",
-        Left:         "│",
-        Right:        "│",
-        TopLeft:      "┌",
-        TopRight:     "┐",
-        BottomLeft:   "└",
-        BottomRight:  "┘",
-        MiddleLeft:   "├",
-        MiddleRight:  "┤",
-        Middle:       "┼",
-        MiddleTop:    "┬",
-        MiddleBottom: "┴",
-    }
-
-    roundedBorder = Border{
-        Top:          "─",
-        Bottom:       "─",
-        Left:         "│",
-        Right:        "│",
-        TopLeft:      "╭",
-        TopRight:     "╮",
-        BottomLeft:   "╰",
-        BottomRight:  "╯",
-        MiddleLeft:   "├",
-        MiddleRight:  "┤",
-        Middle:       "┼",
-        MiddleTop:    "┬",
-        MiddleBottom: "┴",
-    }
-
-    blockBorder = Border{
-        Top:         "█",
-        Bottom:      "█",
-        Left:        "█",
-        Right:       "█",
-        TopLeft:     "█",
-        TopRight:    "█",
-        BottomLeft:  "█",
-        BottomRight: "█",
-    }
-
-    outerHalfBlockBorder = Border{
-        Top:         "▀",
-        Bottom:      "▄",
-        Left:        "▌",
-        Right:       "▐",
-        TopLeft:     "▛",
-        TopRight:    "▜",
-        BottomLeft:  "▙",
-        BottomRight: "▟",
-    }
-
-    innerHalfBlockBorder = Border{
-        Top:         "▄",
-        Bottom:      "▀",
-        Left:        "▐",
-        Right:       "▌",
-        TopLeft:     "▗",
-        TopRight:    "▖",
-        BottomLeft:  "▝",
-        BottomRight: "▘",
-    }
-
-    thickBorder = Border{
-        Top:          "━",
-        Bottom:       "━",
-        Left:         "┃",
-        Right:        "┃",
-        TopLeft:      "┏",
-        TopRight:     "┓",
-        BottomLeft:   "┗",
-        BottomRight:  "┛",
-        MiddleLeft:   "┣",
-        MiddleRight:  "┫",
-        Middle:       "╋",
-        MiddleTop:    "┳",
-        MiddleBottom: "┻",
+impl Border {
+    /// Return the border characters for `style`.
+    pub fn style(style: BorderStyle) -> Self {
+        match style {
+            BorderStyle::Rounded => Self::from_glyphs([
+                "─", "─", "│", "│", "╭", "╮", "╰", "╯", "├", "┤", "┼", "┬", "┴",
+            ]),
+            BorderStyle::Normal => Self::from_glyphs([
+                "─", "─", "│", "│", "┌", "┐", "└", "┘", "├", "┤", "┼", "┬", "┴",
+            ]),
+            BorderStyle::Thick => Self::from_glyphs([
+                "━", "━", "┃", "┃", "┏", "┓", "┗", "┛", "┣", "┫", "╋", "┳", "┻",
+            ]),
+            BorderStyle::Double => Self::from_glyphs([
+                "═", "═", "║", "║", "╔", "╗", "╚", "╝", "╠", "╣", "╬", "╦", "╩",
+            ]),
+            BorderStyle::Block => Self::from_glyphs(["█"; 13]),
+            BorderStyle::Hidden => Self::from_glyphs([" "; 13]),
+        }
     }
 
-    doubleBorder = Border{
-        Top:          "═",
-        Bottom:       "═",
-        Left:         "║",
-        Right:        "║",
-        TopLeft:      "╔",
-        TopRight:     "╗",
-        BottomLeft:   "╚",
-        BottomRight:  "╝",
-        MiddleLeft:   "╠",
-        MiddleRight:  "╣",
-        Middle:       "╬",
-        MiddleTop:    "╦",
-        MiddleBottom: "╩",
+    /// Build a border from its glyphs in field declaration order.
+    fn from_glyphs(glyphs: [&'static str; 13]) -> Self {
+        Self {
+            top: glyphs[0],
+            bottom: glyphs[1],
+            left: glyphs[2],
+            right: glyphs[3],
+            top_left: glyphs[4],
+            top_right: glyphs[5],
+            bottom_left: glyphs[6],
+            bottom_right: glyphs[7],
+            middle_left: glyphs[8],
+            middle_right: glyphs[9],
+            middle: glyphs[10],
+            middle_top: glyphs[11],
+            middle_bottom: glyphs[12],
+        }
     }
+}
 
-    hiddenBorder = Border{
-        Top:          " ",
-        Bottom:       " ",
-        Left:         " ",
-        Right:        " ",
-        TopLeft:      " ",
-        TopRight:     " ",
-        BottomLeft:   " ",
-        BottomRight:  " ",
-        MiddleLeft:   " ",
-        MiddleRight:  " ",
-        Middle:       " ",
-        MiddleTop:    " ",
-        MiddleBottom: " ",
+impl Default for Border {
+    fn default() -> Self {
+        Self::style(BorderStyle::Rounded)
     }
-)
-*/
+}
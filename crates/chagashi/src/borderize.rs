@@ -1,18 +1,46 @@
-use matcha::{fill_by_space, remove_escape_sequences, style, Color, Model, Stylize};
+use matcha::{
+    fill_by_space, remove_escape_sequences, style, truncate, Align, Color, Model, Stylize,
+};
 use std::fmt::Display;
 use unicode_width::UnicodeWidthStr;
 
-use crate::border::Border;
+use crate::border::{Border, BorderStyle};
 
 #[derive(Debug, Default)]
 /// Options for an individual border side.
 pub struct BorderOption {
     /// Whether to render this border side.
     pub show: bool,
-    /// Optional color applied to the border glyphs.
+    /// Optional color applied to the border glyphs. When set, it wins over
+    /// [`Borderize::focus_color`] and [`Borderize::blur_color`].
+    ///
+    /// The top and bottom lines include their corners.
     pub color: Option<Color>,
 }
 
+impl BorderOption {
+    /// Apply this side's color to `glyphs`, or `fallback` when the side has none.
+    fn paint(&self, glyphs: &str, fallback: Option<Color>) -> String {
+        match self.color.or(fallback) {
+            Some(c) => style(glyphs).with(c).to_string(),
+            None => glyphs.to_string(),
+        }
+    }
+}
+
+/// Space between a border and the child content, in cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    /// Blank lines above the content.
+    pub top: u16,
+    /// Spaces after each content line.
+    pub right: u16,
+    /// Blank lines below the content.
+    pub bottom: u16,
+    /// Spaces before each content line.
+    pub left: u16,
+}
+
 /// Render a child model with optional borders around it.
 ///
 /// This widget is useful for composing "window" style components in TUIs.
@@ -25,8 +53,27 @@ pub struct Borderize<M> {
     pub bottom: BorderOption,
     /// Left border option.
     pub left: BorderOption,
-    /// Fixed inner width. If `None`, width is derived from child content.
+    /// Fixed inner width, including padding. If `None`, width is derived from child
+    /// content.
     pub width: Option<u16>,
+    /// Space between the border and the child content.
+    pub padding: Padding,
+    /// Characters used to draw the border.
+    pub border_style: BorderStyle,
+    /// Optional title inset into the top border.
+    pub title: Option<String>,
+    /// Where the title sits along the top border.
+    pub title_alignment: Align,
+    /// Optional footer inset into the bottom border.
+    pub footer: Option<String>,
+    /// Where the footer sits along the bottom border.
+    pub footer_alignment: Align,
+    /// Whether the border is drawn with `focus_color` or `blur_color`.
+    pub focused: bool,
+    /// Border color while focused.
+    pub focus_color: Option<Color>,
+    /// Border color while not focused.
+    pub blur_color: Option<Color>,
     /// Inner child model.
     pub child: M,
 }
@@ -41,6 +88,15 @@ impl<M: Model> Borderize<M> {
             left: BorderOption::default(),
             child,
             width: None,
+            padding: Padding::default(),
+            border_style: BorderStyle::default(),
+            title: None,
+            title_alignment: Align::Left,
+            footer: None,
+            footer_alignment: Align::Right,
+            focused: false,
+            focus_color: None,
+            blur_color: None,
         }
     }
 
@@ -52,6 +108,102 @@ impl<M: Model> Borderize<M> {
         }
     }
 
+    /// Set the space between the border and the child content on each side.
+    pub fn padding(self, top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        Self {
+            padding: Padding {
+                top,
+                right,
+                bottom,
+                left,
+            },
+            ..self
+        }
+    }
+
+    /// Set the same padding on all four sides.
+    pub fn padding_all(self, n: u16) -> Self {
+        self.padding(n, n, n, n)
+    }
+
+    /// Set the characters used to draw the border. Defaults to [`BorderStyle::Rounded`].
+    pub fn border_style(self, style: BorderStyle) -> Self {
+        Self {
+            border_style: style,
+            ..self
+        }
+    }
+
+    /// Set a title to render inside the top border, e.g. `╭─ Logs ───╮`.
+    ///
+    /// The title may be styled. It is truncated when it doesn't fit the inner width, and
+    /// only shows when the top border is shown.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    /// Set where the title sits along the top border.
+    pub fn title_alignment(self, align: Align) -> Self {
+        Self {
+            title_alignment: align,
+            ..self
+        }
+    }
+
+    /// Set a footer to render inside the bottom border, e.g. `╰──── 3 lines ─╯`.
+    ///
+    /// Like [`Borderize::title`], the footer may be styled, is truncated when it doesn't
+    /// fit, and only shows when the bottom border is shown.
+    pub fn footer(self, footer: impl Into<String>) -> Self {
+        Self {
+            footer: Some(footer.into()),
+            ..self
+        }
+    }
+
+    /// Set where the footer sits along the bottom border. Defaults to [`Align::Right`].
+    pub fn footer_alignment(self, align: Align) -> Self {
+        Self {
+            footer_alignment: align,
+            ..self
+        }
+    }
+
+    /// Set whether the border is focused, switching between [`Borderize::focus_color`] and
+    /// [`Borderize::blur_color`]. Call it from the parent's `update`, e.g.
+    /// `borderize.focused(self.is_active)`.
+    pub fn focused(self, focused: bool) -> Self {
+        Self { focused, ..self }
+    }
+
+    /// Set the color of sides without their own [`BorderOption::color`] while focused.
+    pub fn focus_color(self, color: Color) -> Self {
+        Self {
+            focus_color: Some(color),
+            ..self
+        }
+    }
+
+    /// Set the color of sides without their own [`BorderOption::color`] while not focused.
+    pub fn blur_color(self, color: Color) -> Self {
+        Self {
+            blur_color: Some(color),
+            ..self
+        }
+    }
+
+    /// The color for sides without their own color, based on the focus state.
+    fn state_color(&self) -> Option<Color> {
+        if self.focused {
+            self.focus_color
+        } else {
+            self.blur_color
+        }
+    }
+
     /// Configure the top border.
     pub fn top(self, b: BorderOption) -> Self {
         Self { top: b, ..self }
@@ -73,6 +225,59 @@ impl<M: Model> Borderize<M> {
     }
 }
 
+impl BorderOption {
+    /// Render a horizontal border line of inner width `w` between two corners, with
+    /// `text` inset at `align` when there is room for it.
+    fn edge(
+        &self,
+        corners: (&str, &str),
+        glyph: &str,
+        w: u16,
+        text: Option<&str>,
+        align: Align,
+        fallback: Option<Color>,
+    ) -> String {
+        let (left, right) = corners;
+        match inset_run(glyph, w, text, align) {
+            Some((lead, text, trail)) => format!(
+                "{}{}{}",
+                self.paint(&format!("{left}{lead} "), fallback),
+                text,
+                self.paint(&format!(" {trail}{right}"), fallback)
+            ),
+            None => self.paint(
+                &format!("{}{}{}", left, glyph.repeat(w as usize), right),
+                fallback,
+            ),
+        }
+    }
+}
+
+/// Split a horizontal run of width `w` into the glyphs before `text`, the text and the
+/// glyphs after it. The text is padded by a space on each side and always keeps at
+/// least one glyph on either end, so `None` is returned when it has no room.
+fn inset_run(
+    glyph: &str,
+    w: u16,
+    text: Option<&str>,
+    align: Align,
+) -> Option<(String, String, String)> {
+    let text = text.filter(|t| !t.is_empty())?;
+    let room = w.checked_sub(4).filter(|room| *room > 0)?;
+    let text = truncate(text, room, "…");
+    let rest = w - (remove_escape_sequences(&text).width() as u16 + 2);
+    let lead = match align {
+        Align::Left => 1,
+        Align::Center => rest / 2,
+        Align::Right => rest - 1,
+    };
+    Some((
+        glyph.repeat(lead as usize),
+        text,
+        glyph.repeat((rest - lead) as usize),
+    ))
+}
+
 impl<M: Model> Model for Borderize<M> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn init(self, input: &matcha::InitInput) -> (Self, Option<matcha::Cmd>) {
@@ -89,35 +294,45 @@ impl<M: Model> Model for Borderize<M> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn view(&self) -> impl Display {
         let c = self.child.view().to_string();
-        let lines: Vec<String> = c.split('\n').map(|c| c.to_string()).collect();
+        let p = self.padding;
+        let horizontal = p.left.saturating_add(p.right);
+        let child_lines = c.split('\n');
         let w = self.width.unwrap_or_else(|| {
-            lines
-                .iter()
+            let content = child_lines
+                .clone()
                 .map(|line| remove_escape_sequences(line).width())
                 .max()
-                .unwrap_or_default() as u16
+                .unwrap_or_default() as u16;
+            content.saturating_add(horizontal)
         });
+        let content_w = w.saturating_sub(horizontal);
+        let blank = || " ".repeat(w as usize);
+        let lines: Vec<String> = std::iter::repeat_with(blank)
+            .take(p.top as usize)
+            .chain(child_lines.map(|line| {
+                format!(
+                    "{}{}{}",
+                    " ".repeat(p.left as usize),
+                    fill_by_space(line.to_string(), content_w),
+                    " ".repeat(p.right as usize)
+                )
+            }))
+            .chain(std::iter::repeat_with(blank).take(p.bottom as usize))
+            .collect();
 
-        let b = Border::default();
+        let b = Border::style(self.border_style);
+        let color = self.state_color();
 
         let mut lines: Vec<String> = lines
             .into_iter()
             .map(|line| {
                 let left: String = if self.left.show {
-                    if let Some(c) = self.left.color {
-                        style(&b.left).with(c).to_string()
-                    } else {
-                        b.left.to_string()
-                    }
+                    self.left.paint(b.left, color)
                 } else {
                     "".to_string()
                 };
                 let right: String = if self.right.show {
-                    if let Some(c) = self.right.color {
-                        style(&b.right).with(c).to_string()
-                    } else {
-                        b.right.to_string()
-                    }
+                    self.right.paint(b.right, color)
                 } else {
                     "".to_string()
                 };
@@ -129,18 +344,15 @@ impl<M: Model> Model for Borderize<M> {
         if self.top.show {
             let left_corner = if self.left.show { b.top_left } else { b.top };
             let right_corner = if self.right.show { b.top_right } else { b.top };
-            let raw = format!(
-                "{}{}{}",
-                left_corner,
-                b.top.repeat(w as usize),
-                right_corner
+            let top = self.top.edge(
+                (left_corner, right_corner),
+                b.top,
+                w,
+                self.title.as_deref(),
+                self.title_alignment,
+                color,
             );
-            let rendered = if let Some(c) = self.top.color {
-                style(&raw).with(c).to_string()
-            } else {
-                raw
-            };
-            lines.insert(0, rendered);
+            lines.insert(0, top);
         }
 
         if self.bottom.show {
@@ -154,19 +366,193 @@ impl<M: Model> Model for Borderize<M> {
             } else {
                 b.bottom
             };
-            let raw = format!(
-                "{}{}{}",
-                left_corner,
-                b.bottom.repeat(w as usize),
-                right_corner
-            );
-            let rendered = if let Some(c) = self.bottom.color {
-                style(&raw).with(c).to_string()
-            } else {
-                raw
-            };
-            lines.push(rendered);
+            lines.push(self.bottom.edge(
+                (left_corner, right_corner),
+                b.bottom,
+                w,
+                self.footer.as_deref(),
+                self.footer_alignment,
+                color,
+            ));
         }
         lines.join("\n")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Text(&'static str);
+
+    impl Model for Text {
+        fn view(&self) -> impl Display {
+            self.0
+        }
+    }
+
+    fn side(color: Color) -> BorderOption {
+        BorderOption {
+            show: true,
+            color: Some(color),
+        }
+    }
+
+    #[test]
+    fn each_side_uses_its_own_color() {
+        let view = Borderize::new(Text("ab"))
+            .top(side(Color::Red))
+            .right(side(Color::Green))
+            .bottom(side(Color::Blue))
+            .left(side(Color::Yellow))
+            .view()
+            .to_string();
+        let lines: Vec<&str> = view.lines().collect();
+
+        let paint = |s: &str, c: Color| style(s).with(c).to_string();
+        assert_eq!(lines[0], paint("╭──╮", Color::Red));
+        assert_eq!(
+            lines[1],
+            format!(
+                "{}ab{}",
+                paint("│", Color::Yellow),
+                paint("│", Color::Green)
+            )
+        );
+        assert_eq!(lines[2], paint("╰──╯", Color::Blue));
+    }
+
+    #[test]
+    fn focus_switches_colors_unless_a_side_has_its_own() {
+        let boxed = |focused: bool| {
+            Borderize::new(Text("ab"))
+                .top(shown())
+                .bottom(side(Color::Blue))
+                .focus_color(Color::Red)
+                .blur_color(Color::DarkGrey)
+                .focused(focused)
+                .view()
+                .to_string()
+        };
+        let paint = |s: &str, c: Color| style(s).with(c).to_string();
+
+        let view = boxed(true);
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0], paint("────", Color::Red));
+        assert_eq!(lines[2], paint("────", Color::Blue));
+
+        let view = boxed(false);
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0], paint("────", Color::DarkGrey));
+        assert_eq!(lines[2], paint("────", Color::Blue));
+    }
+
+    fn top_line(b: Borderize<Text>) -> String {
+        let view = b.view().to_string();
+        view.lines().next().unwrap().to_string()
+    }
+
+    fn shown() -> BorderOption {
+        BorderOption {
+            show: true,
+            color: None,
+        }
+    }
+
+    #[test]
+    fn padding_keeps_the_box_rectangular() {
+        let view = Borderize::new(Text("ab\nc"))
+            .padding(1, 2, 0, 1)
+            .top(shown())
+            .right(shown())
+            .bottom(shown())
+            .left(shown())
+            .view()
+            .to_string();
+        assert_eq!(view, "╭─────╮\n│     │\n│ ab  │\n│ c   │\n╰─────╯");
+
+        let view = Borderize::new(Text("ab"))
+            .padding_all(1)
+            .top(shown())
+            .bottom(shown())
+            .view()
+            .to_string();
+        assert_eq!(view, "──────\n    \n ab \n    \n──────");
+    }
+
+    #[test]
+    fn border_style_selects_glyphs() {
+        let view = Borderize::new(Text("ab"))
+            .border_style(BorderStyle::Double)
+            .top(shown())
+            .right(shown())
+            .bottom(shown())
+            .left(shown())
+            .view()
+            .to_string();
+        assert_eq!(view, "╔══╗\n║ab║\n╚══╝");
+    }
+
+    #[test]
+    fn title_is_inset_at_its_alignment() {
+        let boxed = || {
+            Borderize::new(Text(""))
+                .width(12)
+                .top(shown())
+                .left(shown())
+                .right(shown())
+                .title("Logs")
+        };
+        assert_eq!(top_line(boxed()), "╭─ Logs ─────╮");
+        assert_eq!(
+            top_line(boxed().title_alignment(Align::Center)),
+            "╭─── Logs ───╮"
+        );
+        assert_eq!(
+            top_line(boxed().title_alignment(Align::Right)),
+            "╭───── Logs ─╮"
+        );
+    }
+
+    #[test]
+    fn footer_is_inset_into_the_bottom_border() {
+        let boxed = || {
+            Borderize::new(Text("a\nb\nc"))
+                .width(13)
+                .right(shown())
+                .bottom(shown())
+                .left(shown())
+                .footer("3 lines")
+        };
+        let bottom = |b: Borderize<Text>| b.view().to_string().lines().last().unwrap().to_string();
+        assert_eq!(bottom(boxed()), "╰─── 3 lines ─╯");
+        assert_eq!(
+            bottom(boxed().footer_alignment(Align::Center)),
+            "╰── 3 lines ──╯"
+        );
+    }
+
+    #[test]
+    fn styled_title_keeps_corners_aligned_and_truncates() {
+        let title = style("Logs").with(Color::Red).to_string();
+        let line = top_line(
+            Borderize::new(Text(""))
+                .width(10)
+                .top(shown())
+                .left(shown())
+                .right(shown())
+                .title(title.clone()),
+        );
+        assert_eq!(line, format!("╭─ {title} ───╮"));
+
+        let line = top_line(
+            Borderize::new(Text(""))
+                .width(7)
+                .top(shown())
+                .left(shown())
+                .right(shown())
+                .title("Messages"),
+        );
+        assert_eq!(line, "╭─ Me… ─╮");
+    }
+}
@@ -27,6 +27,8 @@ pub struct Cursor {
     text_color: Option<Color>,
     /// canceled blink tag
     canceled_tag: AtomicUsize,
+    /// hardware cursor shape requested on focus
+    shape: Option<CursorShape>,
 }
 
 const DEFAULT_BLINK_SPEED: Duration = Duration::from_millis(530);
@@ -112,6 +114,7 @@ impl Default for Cursor {
             mode: CursorMode::Blink,
             text_color: None,
             canceled_tag: AtomicUsize::new(0),
+            shape: None,
         }
     }
 }
@@ -156,6 +159,15 @@ impl Cursor {
         Self { blink: v, ..self }
     }
 
+    /// shape sets the hardware cursor shape requested when the cursor is focused.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn shape(self, shape: CursorShape) -> Self {
+        Self {
+            shape: Some(shape),
+            ..self
+        }
+    }
+
     /// reset_text_color sets the character color under the cursor.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn reset_text_color(self) -> Self {
@@ -207,7 +219,9 @@ impl Cursor {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn focus(self) -> (Self, Option<Cmd>) {
         // if self.mode == CursorMode::Blink && self.focus {
-        let (new, cmd) = self.blink_cmd();
+        let (new, blink_cmd) = self.blink_cmd();
+        let shape_cmd = new.shape.map(|shape| sync!(set_cursor_shape(shape)));
+        let cmd = cmds(shape_cmd.into_iter().chain(blink_cmd));
         (
             Self {
                 focus: true,
@@ -1,9 +1,19 @@
 use std::fmt::Display;
 
-use matcha::{batch, fill_by_space, Cmd, InitInput, Model, Msg, ResizeEvent};
+use matcha::{fill_by_space, Cmd, InitInput, Model, Msg, ResizeEvent};
 
 use matcha::DynModel;
 
+/// Sent by [`Flex`] to each child with the width (in cells) of the column it is laid
+/// out in.
+///
+/// `Flex` sends it right after the child has handled `init` and after every
+/// [`ResizeEvent`], so it always arrives after the terminal-wide size and a child can
+/// let it take precedence. The child's view is still clamped and padded to this width,
+/// so children that ignore the message keep rendering as before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetWidth(pub u16);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Layout direction for [`Flex`].
 pub enum FlexDirection {
@@ -13,6 +23,43 @@ pub enum FlexDirection {
     Column,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Vertical placement of a child shorter than its row in [`Flex`].
+pub enum AlignItems {
+    /// Place the child at the top of the row.
+    #[default]
+    Start,
+    /// Center the child vertically within the row.
+    Center,
+    /// Place the child at the bottom of the row.
+    End,
+    /// Fill the full height of the row.
+    ///
+    /// Children render their own view, so the content starts at the top and the
+    /// remaining lines are blank cells of the child's width, keeping the column
+    /// rectangular.
+    Stretch,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Placement of leftover horizontal space in a [`Flex`] row.
+///
+/// Columns fill the row unless they are all fixed (see [`Flex::weights`]), so this
+/// only matters when the columns are narrower than the available width.
+pub enum Justify {
+    /// Pack columns to the left.
+    #[default]
+    Start,
+    /// Center the columns as a group.
+    Center,
+    /// Pack columns to the right.
+    End,
+    /// Put all leftover space into the gaps between columns.
+    SpaceBetween,
+    /// Give each column the same space on both sides.
+    SpaceAround,
+}
+
 #[derive(Debug, Clone)]
 /// Configuration for [`Flex`].
 pub struct FlexOption {
@@ -20,23 +67,38 @@ pub struct FlexOption {
     /// is reduced and items will wrap to the next row.
     pub min_item_width: u16,
     /// Horizontal gap between columns (in cells).
-    pub gap: u16,
+    pub column_gap: u16,
+    /// Vertical gap between wrapped rows in row layout, and between children in column
+    /// layout (in lines).
+    pub row_gap: u16,
     /// Enable wrapping when the available width is insufficient.
     pub wrap: bool,
     /// Maximum number of columns. If not set, `children.len()` is used as the upper bound.
     pub columns: Option<u16>,
     /// Layout direction.
     pub direction: FlexDirection,
+    /// Default vertical alignment of children in row layout.
+    pub align_items: AlignItems,
+    /// Placement of leftover horizontal space in row layout.
+    pub justify: Justify,
+    /// Cut column layout off at the available height. Leave this off when the `Flex` sits
+    /// inside a [`Viewport`](crate::viewport::Viewport), which needs the full content to
+    /// scroll through.
+    pub clamp_height: bool,
 }
 
 impl Default for FlexOption {
     fn default() -> Self {
         Self {
             min_item_width: 12,
-            gap: 1,
+            column_gap: 1,
+            row_gap: 0,
             wrap: true,
             columns: None,
             direction: FlexDirection::Row,
+            align_items: AlignItems::Start,
+            justify: Justify::Start,
+            clamp_height: false,
         }
     }
 }
@@ -45,10 +107,15 @@ impl Default for FlexOption {
 ///
 /// - Renders children horizontally (row) or vertically (column)
 /// - When `wrap=true` in row mode, it reduces the number of columns based on terminal width
+/// - In column mode, output is cut off at the terminal height when
+///   [`FlexOption::clamp_height`] is enabled
 /// - Uses `matcha::formatter` utilities for width-aware clamp/padding
 pub struct Flex {
     width: u16,
+    height: u16,
     opt: FlexOption,
+    item_align: Vec<Option<AlignItems>>,
+    weights: Vec<u16>,
     children: Vec<Box<dyn DynModel>>,
 }
 
@@ -57,7 +124,10 @@ impl Flex {
     pub fn new(children: Vec<Box<dyn DynModel>>) -> Self {
         Self {
             width: 0,
+            height: 0,
             opt: FlexOption::default(),
+            item_align: vec![],
+            weights: vec![],
             children,
         }
     }
@@ -67,10 +137,30 @@ impl Flex {
         Self { opt, ..self }
     }
 
-    /// Set the gap (in cells) between children.
+    /// Set both the row and the column gap.
     pub fn gap(self, gap: u16) -> Self {
+        self.row_gap(gap).column_gap(gap)
+    }
+
+    /// Set the horizontal gap (in cells) between columns.
+    pub fn column_gap(self, column_gap: u16) -> Self {
         Self {
-            opt: FlexOption { gap, ..self.opt },
+            opt: FlexOption {
+                column_gap,
+                ..self.opt
+            },
+            ..self
+        }
+    }
+
+    /// Set the vertical gap (in lines) between rows, or between children in column
+    /// layout.
+    pub fn row_gap(self, row_gap: u16) -> Self {
+        Self {
+            opt: FlexOption {
+                row_gap,
+                ..self.opt
+            },
             ..self
         }
     }
@@ -116,6 +206,72 @@ impl Flex {
         }
     }
 
+    /// Set the default vertical alignment of children in row layout.
+    pub fn align_items(self, align_items: AlignItems) -> Self {
+        Self {
+            opt: FlexOption {
+                align_items,
+                ..self.opt
+            },
+            ..self
+        }
+    }
+
+    /// Set how leftover horizontal space is placed in row layout.
+    pub fn justify(self, justify: Justify) -> Self {
+        Self {
+            opt: FlexOption {
+                justify,
+                ..self.opt
+            },
+            ..self
+        }
+    }
+
+    /// Enable/disable cutting column layout off at the available height.
+    ///
+    /// Keep this `false` when wrapping the `Flex` in a [`Viewport`](crate::viewport::Viewport)
+    /// so the viewport receives every line and handles the scrolling.
+    pub fn clamp_height(self, clamp_height: bool) -> Self {
+        Self {
+            opt: FlexOption {
+                clamp_height,
+                ..self.opt
+            },
+            ..self
+        }
+    }
+
+    /// Override the vertical alignment per child, by index.
+    ///
+    /// `None` (or a missing entry) falls back to [`FlexOption::align_items`].
+    pub fn with_item_align(self, item_align: Vec<Option<AlignItems>>) -> Self {
+        Self { item_align, ..self }
+    }
+
+    /// Set how columns in a row share the available width, per child by index.
+    ///
+    /// Each column gets [`FlexOption::min_item_width`] first and the rest is split in
+    /// proportion to the weights. A weight of 0 keeps a column at exactly
+    /// `min_item_width`, e.g. `weights(vec![0, 1])` for a fixed sidebar and a growing
+    /// main pane. Missing entries default to 1, so without weights every column in a
+    /// row gets the same width.
+    pub fn weights(self, weights: Vec<u16>) -> Self {
+        Self { weights, ..self }
+    }
+
+    fn weight_for(&self, index: usize) -> u16 {
+        self.weights.get(index).copied().unwrap_or(1)
+    }
+
+    fn align_for(&self, index: usize) -> AlignItems {
+        self.item_align
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(self.opt.align_items)
+    }
+
     fn compute_columns(&self, available_width: u16) -> usize {
         let count = self.children.len();
         if count == 0 {
@@ -137,7 +293,10 @@ impl Flex {
         // `min_item_width`.
         for cols in (1..=max_cols).rev() {
             let cols_u16 = cols as u16;
-            let gaps = self.opt.gap.saturating_mul(cols_u16.saturating_sub(1));
+            let gaps = self
+                .opt
+                .column_gap
+                .saturating_mul(cols_u16.saturating_sub(1));
             let required = self.opt.min_item_width.saturating_mul(cols_u16) + gaps;
             if required <= available_width {
                 return cols;
@@ -146,26 +305,119 @@ impl Flex {
         1
     }
 
-    fn widths_for_row(&self, available_width: u16, cols: usize) -> Vec<u16> {
+    /// Width of every child's column, in child order.
+    fn child_widths(&self) -> Vec<u16> {
+        match self.opt.direction {
+            FlexDirection::Column => vec![self.width; self.children.len()],
+            FlexDirection::Row => {
+                let cols = self.compute_columns(self.width).max(1);
+                let mut widths = Vec::with_capacity(self.children.len());
+                for start in (0..self.children.len()).step_by(cols) {
+                    let end = (start + cols).min(self.children.len());
+                    let weights: Vec<u16> = (start..end).map(|i| self.weight_for(i)).collect();
+                    widths.extend(self.widths_for_row(self.width, &weights));
+                }
+                widths
+            }
+        }
+    }
+
+    /// Send each child a [`SetWidth`] with its current column width.
+    fn forward_widths(self, cmds: &mut Vec<Cmd>) -> Self {
+        let widths = self.child_widths();
+        let children = self
+            .children
+            .into_iter()
+            .zip(widths)
+            .map(|(c, w)| {
+                let (c, cmd) = c.update_box(&(Box::new(SetWidth(w)) as Msg));
+                cmds.extend(cmd);
+                c
+            })
+            .collect();
+        Self { children, ..self }
+    }
+
+    fn widths_for_row(&self, available_width: u16, weights: &[u16]) -> Vec<u16> {
+        let cols = weights.len();
         if cols == 0 {
             return vec![];
         }
-        if cols == 1 {
+        if cols == 1 && weights[0] != 0 {
             return vec![available_width];
         }
 
         let cols_u16 = cols as u16;
-        let gaps = self.opt.gap.saturating_mul(cols_u16.saturating_sub(1));
-        let usable = available_width.saturating_sub(gaps);
-        let base = usable / cols_u16;
-        let rem = usable % cols_u16;
+        let gaps = self
+            .opt
+            .column_gap
+            .saturating_mul(cols_u16.saturating_sub(1));
+        let mut usable = available_width.saturating_sub(gaps);
 
-        (0..cols)
-            .map(|i| base + if (i as u16) < rem { 1 } else { 0 })
-            .collect()
+        // Zero-weight columns are fixed at the minimum width.
+        let mut widths: Vec<u16> = weights
+            .iter()
+            .map(|&weight| {
+                if weight != 0 {
+                    return 0;
+                }
+                let w = self.opt.min_item_width.min(usable);
+                usable -= w;
+                w
+            })
+            .collect();
+
+        let grow: Vec<usize> = (0..cols).filter(|&i| weights[i] != 0).collect();
+        if grow.is_empty() {
+            return widths;
+        }
+        let base = self.opt.min_item_width.min(usable / grow.len() as u16);
+        let extra = usable - base * grow.len() as u16;
+        let total_weight: u32 = grow.iter().map(|&i| weights[i] as u32).sum();
+        let mut left = extra;
+        for &i in &grow {
+            let share = (extra as u32 * weights[i] as u32 / total_weight) as u16;
+            widths[i] = base + share;
+            left -= share;
+        }
+        // Hand out what rounding left over from the left.
+        for &i in grow.iter().take(left as usize) {
+            widths[i] += 1;
+        }
+        widths
     }
 
-    fn render_row(&self, row: &[&dyn DynModel], widths: &[u16]) -> Vec<String> {
+    /// Returns the spaces before the first column and between each pair of columns, so
+    /// `slack` unused cells are placed according to [`FlexOption::justify`].
+    fn spacing(&self, slack: u16, cols: usize) -> (u16, Vec<u16>) {
+        let gap = self.opt.column_gap;
+        let gaps = cols.saturating_sub(1);
+        match self.opt.justify {
+            Justify::Start => (0, vec![gap; gaps]),
+            Justify::Center => (slack / 2, vec![gap; gaps]),
+            Justify::End => (slack, vec![gap; gaps]),
+            Justify::SpaceBetween if gaps > 0 => {
+                let (each, rem) = (slack / gaps as u16, slack % gaps as u16);
+                let spacing = (0..gaps as u16)
+                    .map(|i| gap + each + u16::from(i < rem))
+                    .collect();
+                (0, spacing)
+            }
+            Justify::SpaceBetween => (0, vec![]),
+            Justify::SpaceAround => {
+                let each = slack / cols.max(1) as u16;
+                (each / 2, vec![gap + each; gaps])
+            }
+        }
+    }
+
+    fn render_row(
+        &self,
+        row: &[&dyn DynModel],
+        aligns: &[AlignItems],
+        widths: &[u16],
+        available_width: u16,
+    ) -> Vec<String> {
         let child_lines: Vec<Vec<String>> = row
             .iter()
             .map(|c| c.view_string().split('\n').map(|s| s.to_string()).collect())
@@ -177,17 +429,44 @@ impl Flex {
             .max()
             .unwrap_or(0);
 
+        // Number of blank lines above each child.
+        let tops: Vec<usize> = child_lines
+            .iter()
+            .enumerate()
+            .map(|(col_idx, lines)| {
+                let slack = height - lines.len();
+                match aligns.get(col_idx).copied().unwrap_or_default() {
+                    AlignItems::Start | AlignItems::Stretch => 0,
+                    AlignItems::Center => slack / 2,
+                    AlignItems::End => slack,
+                }
+            })
+            .collect();
+
+        let used = widths.iter().sum::<u16>()
+            + self
+                .opt
+                .column_gap
+                .saturating_mul(widths.len().saturating_sub(1) as u16);
+        let (lead, gaps) = self.spacing(available_width.saturating_sub(used), widths.len());
+
         let mut out = Vec::with_capacity(height);
         for line_idx in 0..height {
-            let mut parts = Vec::with_capacity(row.len());
+            let mut line = " ".repeat(lead as usize);
             for (col_idx, lines) in child_lines.iter().enumerate() {
                 let w = *widths.get(col_idx).unwrap_or(&0);
-                let raw = lines.get(line_idx).map(|s| s.as_str()).unwrap_or("");
+                let raw = line_idx
+                    .checked_sub(tops[col_idx])
+                    .and_then(|i| lines.get(i))
+                    .map(|s| s.as_str())
+                    .unwrap_or("");
+                if let Some(gap) = col_idx.checked_sub(1).and_then(|i| gaps.get(i)) {
+                    line.push_str(&" ".repeat(*gap as usize));
+                }
                 let clamped = matcha::clamp_by(raw, w);
-                let padded = fill_by_space(clamped, w);
-                parts.push(padded);
+                line.push_str(&fill_by_space(clamped, w));
             }
-            out.push(parts.join(&" ".repeat(self.opt.gap as usize)));
+            out.push(line);
         }
         out
     }
@@ -204,26 +483,23 @@ impl Model for Flex {
             }
             children.push(c);
         }
-        let cmd = if cmds.is_empty() {
-            None
-        } else {
-            Some(batch(cmds))
-        };
-        (
-            Self {
-                width: input.size.0,
-                children,
-                ..self
-            },
-            cmd,
-        )
+        let flex = Self {
+            width: input.size.0,
+            height: input.size.1,
+            children,
+            ..self
+        }
+        .forward_widths(&mut cmds);
+        (flex, matcha::cmds(cmds))
     }
 
     fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
         let mut cmds = vec![];
-        let mut width = self.width;
-        if let Some(r) = msg.downcast_ref::<ResizeEvent>() {
+        let (mut width, mut height) = (self.width, self.height);
+        let resized = msg.downcast_ref::<ResizeEvent>();
+        if let Some(r) = resized {
             width = r.0;
+            height = r.1;
         }
 
         let mut children: Vec<Box<dyn DynModel>> = Vec::with_capacity(self.children.len());
@@ -235,19 +511,16 @@ impl Model for Flex {
             children.push(c);
         }
 
-        let cmd = if cmds.is_empty() {
-            None
-        } else {
-            Some(batch(cmds))
+        let mut flex = Self {
+            width,
+            height,
+            children,
+            ..self
         };
-        (
-            Self {
-                width,
-                children,
-                ..self
-            },
-            cmd,
-        )
+        if resized.is_some() {
+            flex = flex.forward_widths(&mut cmds);
+        }
+        (flex, matcha::cmds(cmds))
     }
 
     fn view(&self) -> impl Display {
@@ -263,10 +536,21 @@ impl Model for Flex {
                     return String::new();
                 }
                 let mut lines: Vec<String> = vec![];
-                for chunk in self.children.chunks(cols) {
+                for (chunk_idx, chunk) in self.children.chunks(cols).enumerate() {
+                    if chunk_idx != 0 {
+                        lines.extend(
+                            std::iter::repeat(String::new()).take(self.opt.row_gap as usize),
+                        );
+                    }
                     let row: Vec<&dyn DynModel> = chunk.iter().map(|c| c.as_ref()).collect();
-                    let widths = self.widths_for_row(available_width, row.len());
-                    lines.extend(self.render_row(&row, &widths));
+                    let aligns: Vec<AlignItems> = (0..row.len())
+                        .map(|i| self.align_for(chunk_idx * cols + i))
+                        .collect();
+                    let weights: Vec<u16> = (0..row.len())
+                        .map(|i| self.weight_for(chunk_idx * cols + i))
+                        .collect();
+                    let widths = self.widths_for_row(available_width, &weights);
+                    lines.extend(self.render_row(&row, &aligns, &widths, available_width));
                 }
                 lines.join("\n")
             }
@@ -274,7 +558,9 @@ impl Model for Flex {
                 let mut out: Vec<String> = vec![];
                 for (i, child) in self.children.iter().enumerate() {
                     if i != 0 {
-                        out.extend(std::iter::repeat(String::new()).take(self.opt.gap as usize));
+                        out.extend(
+                            std::iter::repeat(String::new()).take(self.opt.row_gap as usize),
+                        );
                     }
                     let clamped_lines = child
                         .view_string()
@@ -285,6 +571,9 @@ impl Model for Flex {
                         .collect::<Vec<_>>();
                     out.extend(clamped_lines);
                 }
+                if self.opt.clamp_height {
+                    out.truncate(self.height as usize);
+                }
                 out.join("\n")
             }
         }
@@ -333,4 +622,164 @@ mod tests {
         assert_eq!(flex.compute_columns(9), 2);
         assert_eq!(flex.compute_columns(19), 4);
     }
+
+    #[test]
+    fn widths_split_evenly_without_weights() {
+        let flex = Flex::new(vec![]).min_item_width(2).gap(1);
+        assert_eq!(flex.widths_for_row(12, &[1, 1, 1]), vec![4, 3, 3]);
+    }
+
+    #[test]
+    fn weights_grow_columns_proportionally() {
+        let flex = Flex::new(vec![]).min_item_width(4).gap(1);
+        // A zero-weight sidebar stays at the minimum width.
+        assert_eq!(flex.widths_for_row(31, &[0, 1]), vec![4, 26]);
+        // 28 usable cells: 4 each, then 20 split 1:3.
+        assert_eq!(flex.widths_for_row(29, &[1, 3]), vec![9, 19]);
+    }
+
+    #[test]
+    fn row_and_column_gaps_are_separate() {
+        let mut flex = Flex::new(vec![
+            boxed(Static("a")),
+            boxed(Static("b")),
+            boxed(Static("c")),
+        ])
+        .columns(2)
+        .min_item_width(2)
+        .column_gap(2)
+        .row_gap(1);
+        flex.width = 6;
+        let view = flex.view().to_string();
+        assert_eq!(view, "a   b \n\nc     ");
+    }
+
+    #[test]
+    fn rows_are_not_separated_by_default() {
+        let mut flex = Flex::new(vec![
+            boxed(Static("a")),
+            boxed(Static("b")),
+            boxed(Static("c")),
+        ])
+        .columns(2)
+        .min_item_width(2);
+        flex.width = 5;
+        assert_eq!(flex.view().to_string(), "a  b \nc    ");
+
+        flex.opt.direction = FlexDirection::Column;
+        assert_eq!(flex.view().to_string(), "a    \nb    \nc    ");
+    }
+
+    #[test]
+    fn column_layout_is_clamped_to_height() {
+        let flex = Flex::new(vec![
+            boxed(Static("a\nb")),
+            boxed(Static("c")),
+            boxed(Static("d")),
+        ])
+        .direction(FlexDirection::Column)
+        .clamp_height(true);
+        let input = InitInput::new((1, 2));
+        let (flex, _) = flex.init(&input);
+        assert_eq!(flex.view().to_string(), "a\nb");
+
+        let (flex, _) = flex.update(&(Box::new(ResizeEvent(1, 3)) as Msg));
+        assert_eq!(flex.view().to_string(), "a\nb\nc");
+
+        let flex = flex.clamp_height(false);
+        assert_eq!(flex.view().to_string(), "a\nb\nc\nd");
+    }
+
+    #[test]
+    fn column_layout_is_not_clamped_by_default() {
+        let flex = Flex::new(vec![boxed(Static("a\nb")), boxed(Static("c"))])
+            .direction(FlexDirection::Column);
+        let input = InitInput::new((1, 1));
+        let (flex, _) = flex.init(&input);
+        assert_eq!(flex.view().to_string(), "a\nb\nc");
+    }
+
+    /// Renders the last width it was told about.
+    #[derive(Clone)]
+    struct WidthAware(u16);
+    impl Model for WidthAware {
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            match msg.downcast_ref::<SetWidth>() {
+                Some(SetWidth(w)) => (Self(*w), None),
+                None => (self, None),
+            }
+        }
+
+        fn view(&self) -> impl Display {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn forwards_column_widths_to_children() {
+        let flex = Flex::new(vec![
+            boxed(WidthAware(0)),
+            boxed(WidthAware(0)),
+            boxed(Static("c")),
+        ])
+        .min_item_width(2)
+        .columns(2)
+        .weights(vec![0, 1]);
+        let input = InitInput::new((11, 5));
+        let (flex, _) = flex.init(&input);
+        assert_eq!(flex.view().to_string(), "2  8       \nc          ");
+
+        let (flex, _) = flex.update(&(Box::new(ResizeEvent(7, 5)) as Msg));
+        assert_eq!(flex.view().to_string(), "2  4   \nc      ");
+    }
+
+    #[test]
+    fn justify_places_leftover_space() {
+        let render = |justify| {
+            let mut flex = Flex::new(vec![boxed(Static("a")), boxed(Static("b"))])
+                .min_item_width(2)
+                .weights(vec![0, 0])
+                .justify(justify);
+            flex.width = 10;
+            let view = flex.view().to_string();
+            view
+        };
+        assert_eq!(render(Justify::Start), "a  b ");
+        assert_eq!(render(Justify::Center), "  a  b ");
+        assert_eq!(render(Justify::End), "     a  b ");
+        assert_eq!(render(Justify::SpaceBetween), "a       b ");
+        assert_eq!(render(Justify::SpaceAround), " a    b ");
+    }
+
+    #[test]
+    fn align_items_positions_shorter_children() {
+        let render = |align| {
+            let flex = Flex::new(vec![boxed(Static("1\n2\n3")), boxed(Static("x"))])
+                .gap(1)
+                .align_items(align);
+            let row: Vec<&dyn DynModel> = flex.children.iter().map(|c| c.as_ref()).collect();
+            flex.render_row(&row, &[align, align], &[1, 1], 3)
+        };
+        assert_eq!(render(AlignItems::Start), vec!["1 x", "2  ", "3  "]);
+        assert_eq!(render(AlignItems::Center), vec!["1  ", "2 x", "3  "]);
+        assert_eq!(render(AlignItems::End), vec!["1  ", "2  ", "3 x"]);
+        assert_eq!(render(AlignItems::Stretch), vec!["1 x", "2  ", "3  "]);
+    }
+
+    #[test]
+    fn item_align_overrides_container_default() {
+        let flex = Flex::new(vec![
+            boxed(Static("1\n2\n3")),
+            boxed(Static("c")),
+            boxed(Static("s")),
+        ])
+        .gap(1)
+        .with_item_align(vec![None, Some(AlignItems::Center)]);
+        let row: Vec<&dyn DynModel> = flex.children.iter().map(|c| c.as_ref()).collect();
+        let aligns: Vec<AlignItems> = (0..row.len()).map(|i| flex.align_for(i)).collect();
+        assert_eq!(
+            flex.render_row(&row, &aligns, &[1, 1, 1], 5),
+            vec!["1   s", "2 c  ", "3    "]
+        );
+    }
 }
@@ -6,6 +6,7 @@
 //! - Text input / textarea (`textinput`, `textarea`)
 //! - Viewport scrolling (`viewport`)
 //! - Spinners (`spinner`)
+//! - Elapsed timers (`stopwatch`)
 //! - Borders (`border`, `borderize`)
 //!
 //! Most components implement [`matcha::Model`] so they can be composed.
@@ -20,6 +21,8 @@ pub mod flex;
 pub mod list;
 /// Spinner widget.
 pub mod spinner;
+/// Count-up elapsed timer.
+pub mod stopwatch;
 /// Tabs widget.
 pub mod tabs;
 pub mod textarea;
@@ -30,4 +33,4 @@ pub mod viewport;
 
 mod utils;
 
-pub use flex::{Flex, FlexDirection, FlexOption};
+pub use flex::{AlignItems, Flex, FlexDirection, FlexOption, Justify, SetWidth};
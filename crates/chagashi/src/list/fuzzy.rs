@@ -0,0 +1,76 @@
+//! Dependency-free fuzzy matching used by [`FilterMode::Fuzzy`](super::FilterMode::Fuzzy).
+
+/// Only this many chars of a text are scanned, so a huge `filter_value` can't stall
+/// filtering a large list. Matches past the limit are not found.
+const MAX_TEXT_CHARS: usize = 1024;
+
+/// The result of matching a query against a text with [`fuzzy_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better.
+    pub score: i64,
+    /// Char indices in the text of the matched characters.
+    pub indices: Vec<usize>,
+}
+
+pub(super) fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Match `query` against `text` as a case-insensitive subsequence.
+///
+/// Each matched character scores a point, with a bonus when it directly follows the
+/// previous match or starts a word, and a small penalty for every skipped character.
+/// Returns `None` if `text` doesn't contain every query character in order.
+///
+/// Matching is a single greedy pass over at most the first 1024 chars of `text`, so
+/// its cost is bounded regardless of the input.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut query = query.chars().peekable();
+    let mut score = 0;
+    let mut indices = vec![];
+    let mut prev: Option<char> = None;
+    for (i, c) in text.chars().take(MAX_TEXT_CHARS).enumerate() {
+        let Some(&q) = query.peek() else {
+            break;
+        };
+        if eq_ignore_case(c, q) {
+            score += 1;
+            if indices.last().is_some_and(|last| last + 1 == i) {
+                score += 5;
+            }
+            if prev.map_or(true, |p| !p.is_alphanumeric()) {
+                score += 3;
+            }
+            indices.push(i);
+            query.next();
+        } else if !indices.is_empty() {
+            score -= 1;
+        }
+        prev = Some(c);
+    }
+    query
+        .peek()
+        .is_none()
+        .then_some(FuzzyMatch { score, indices })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_and_word_start_matches_score_higher() {
+        let tight = fuzzy_match("ab", "ab").unwrap();
+        let loose = fuzzy_match("ab", "xaxb").unwrap();
+        assert!(tight.score > loose.score);
+        assert_eq!(loose.indices, vec![1, 3]);
+    }
+
+    #[test]
+    fn text_past_the_scan_limit_is_ignored() {
+        let text = "a".repeat(MAX_TEXT_CHARS) + "z";
+        assert!(fuzzy_match("z", &text).is_none());
+        assert!(fuzzy_match("a", &text).is_some());
+    }
+}
@@ -0,0 +1,1717 @@
+//! A list component inspired by Bubble Tea.
+//!
+//! This module is currently a work in progress. The component provides a basic list model
+//! with pagination and optional spinner support.
+
+mod fuzzy;
+
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use fuzzy::eq_ignore_case;
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
+
+use crate::spinner::TickMsg;
+use crate::textinput::TextInput;
+use matcha::KeyCode;
+use matcha::KeyEvent;
+use matcha::{
+    key, style, Cmd, Color as MatchaColor, InitInput, Key, Model as MModel, Msg, Stylize,
+};
+
+/// A matcha-compatible event type used by the list component.
+#[derive(Debug)]
+pub enum Event {
+    /// A keyboard event.
+    Key(KeyEvent),
+    /// A custom event.
+    Other(Box<dyn std::any::Any + Send>),
+}
+
+impl Clone for Event {
+    fn clone(&self) -> Self {
+        match self {
+            Event::Key(key) => Event::Key(*key),
+            Event::Other(_) => Event::Other(Box::new(()) as Box<dyn std::any::Any + Send>),
+        }
+    }
+}
+
+use crate::spinner::{Spinner, SpinnerType};
+
+/// Item is a trait that must be implemented by items that appear in the list.
+pub trait Item: Send + Sync {
+    /// FilterValue is the value we use when filtering against this item.
+    fn filter_value(&self) -> String;
+
+    /// Description is an optional second line shown below the item's title.
+    fn description(&self) -> Option<String> {
+        None
+    }
+}
+
+/// ItemDelegate encapsulates the functionality for all list items.
+pub trait ItemDelegate: Send + Sync {
+    /// Render renders the item's view.
+    fn render(&self, w: &mut dyn Write, model: &Model, index: usize, item: &dyn Item);
+
+    /// Height is the height of the list item.
+    fn height(&self) -> usize;
+
+    /// Spacing is the size of the horizontal gap between list items in cells.
+    fn spacing(&self) -> usize;
+
+    /// Update is the update loop for items.
+    fn update(&self, event: Event, model: &mut Model) -> Option<Event>;
+}
+
+/// A small helper type to make styling ergonomics easier in this crate.
+pub struct StylizeWrapper {
+    /// Raw content to style.
+    pub content: String,
+    /// Optional foreground color.
+    pub fg_color: Option<MatchaColor>,
+    /// Optional background color.
+    pub bg_color: Option<MatchaColor>,
+    /// Whether to render the content in bold.
+    pub bold: bool,
+}
+
+impl StylizeWrapper {
+    /// Create a new wrapper around `content`.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            fg_color: None,
+            bg_color: None,
+            bold: false,
+        }
+    }
+
+    /// Enable bold styling.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Set background color.
+    pub fn bg(mut self, color: MatchaColor) -> Self {
+        self.bg_color = Some(color);
+        self
+    }
+
+    /// Set foreground color.
+    pub fn with(mut self, color: MatchaColor) -> Self {
+        self.fg_color = Some(color);
+        self
+    }
+}
+
+impl Clone for StylizeWrapper {
+    fn clone(&self) -> Self {
+        Self {
+            content: self.content.clone(),
+            fg_color: self.fg_color,
+            bg_color: self.bg_color,
+            bold: self.bold,
+        }
+    }
+}
+
+impl Stylize for StylizeWrapper {
+    type Styled = matcha::crossterm::style::StyledContent<String>;
+
+    fn stylize(self) -> Self::Styled {
+        let mut styled = style(self.content.clone());
+        if self.bold {
+            styled = styled.bold();
+        }
+        if let Some(color) = self.fg_color {
+            styled = styled.with(color);
+        }
+        if let Some(color) = self.bg_color {
+            styled = styled.on(color);
+        }
+        styled
+    }
+}
+
+/// How the filter query is matched against each item's `filter_value`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Keep items containing the query, in their original order.
+    #[default]
+    Substring,
+    /// Keep items containing the query's characters in order, best matches first.
+    Fuzzy,
+}
+
+/// How the pagination line shows the current page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaginationStyle {
+    /// Page numbers, e.g. `2/5`.
+    #[default]
+    Arabic,
+    /// One dot per page with the current one filled, e.g. `○ ● ○ ○ ○`.
+    ///
+    /// Falls back to [`PaginationStyle::Arabic`] when the dots don't fit the width.
+    Dots,
+}
+
+/// Renders the status bar content from the list state.
+type StatusRenderer = Box<dyn Fn(&Model) -> String + Send>;
+
+#[derive(Debug)]
+/// Message that clears a status message set with [`Model::new_status_message`] once
+/// its duration has passed.
+pub struct StatusTimeoutMsg {
+    // Matches `Model::status_message_tag` of the message it was scheduled for.
+    tag: usize,
+}
+
+/// How long type-ahead waits for the next key before starting a new prefix.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+#[derive(Debug)]
+/// Message that clears the type-ahead prefix after a pause in typing.
+pub struct TypeAheadResetMsg {
+    // Matches `Model::type_ahead_tag` of the key it was scheduled for.
+    tag: usize,
+}
+
+/// Builds the application message sent when the selection changes.
+type OnSelect = Box<dyn Fn(usize, &dyn Item) -> Msg + Send>;
+
+#[derive(Debug)]
+/// Message emitted when the selected item changes.
+pub struct ListSelectMsg {
+    /// Index of the selected item among the visible items (0-based).
+    pub index: usize,
+}
+
+/// Model contains the state for the list component
+pub struct Model {
+    // Display options
+    show_title: bool,
+    show_status_bar: bool,
+    show_pagination: bool,
+    show_help: bool,
+    pagination_style: PaginationStyle,
+
+    // Customization
+    item_name_singular: String,
+    item_name_plural: String,
+    title: String,
+
+    // Styling attributes
+    title_style: StylizeWrapper,
+    status_bar_style: StylizeWrapper,
+    selected_item_style: StylizeWrapper,
+    normal_item_style: StylizeWrapper,
+
+    // State
+    width: usize,
+    height: usize,
+    cursor: usize,
+    per_page: usize,
+    page: usize,
+    total_pages: usize,
+
+    // Items
+    items: Vec<Arc<dyn Item>>,
+
+    // Multi-select
+    multi_select: bool,
+    // Indices into `items` (not the visible items), so they survive paging and filtering.
+    selected: HashSet<usize>,
+
+    // Filter
+    filter: String,
+    filter_mode: FilterMode,
+    // Whether the user is typing a query into `filter_input`.
+    filtering: bool,
+    filter_input: TextInput,
+    // `filter_value` of the item the user last selected. Filtering tries to keep
+    // the cursor on this item so the selection survives narrowing and clearing.
+    preferred_selection: Option<String>,
+    // `filter_value` of the item the cursor was clamped to when the preferred
+    // item was filtered out.
+    fallback_selection: Option<String>,
+
+    // Spinner
+    spinner: Spinner,
+    show_spinner: bool,
+
+    // Status message
+    status_message: String,
+    // Bumped by every `new_status_message`, so only the latest timeout clears it.
+    status_message_tag: usize,
+    // Replaces the default "N items" status content when set.
+    status_renderer: Option<StatusRenderer>,
+
+    // Builds an extra message for the parent whenever the selection changes.
+    on_select: Option<OnSelect>,
+
+    // Delegate
+    delegate: Box<dyn ItemDelegate>,
+
+    // This flag determines whether the list should loop around when navigating
+    // beyond the last or first item
+    infinite_scrolling: bool,
+
+    // Type-ahead
+    type_ahead_enabled: bool,
+    type_ahead: String,
+    type_ahead_tag: usize,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        let spinner = Spinner::new(SpinnerType::line());
+
+        Self {
+            show_title: true,
+            show_status_bar: true,
+            show_pagination: true,
+            show_help: true,
+            pagination_style: PaginationStyle::default(),
+
+            item_name_singular: "item".to_string(),
+            item_name_plural: "items".to_string(),
+            title: "List".to_string(),
+
+            title_style: StylizeWrapper::new("").bold(),
+            status_bar_style: StylizeWrapper::new(""),
+            selected_item_style: StylizeWrapper::new("").bg(MatchaColor::Blue),
+            normal_item_style: StylizeWrapper::new(""),
+
+            width: 80,
+            height: 24,
+            cursor: 0,
+            per_page: 10,
+            page: 0,
+            total_pages: 1,
+
+            items: Vec::new(),
+
+            multi_select: false,
+            selected: HashSet::new(),
+
+            filter: String::new(),
+            filter_mode: FilterMode::default(),
+            filtering: false,
+            filter_input: TextInput::new(),
+            preferred_selection: None,
+            fallback_selection: None,
+
+            spinner,
+            show_spinner: false,
+
+            status_message: String::new(),
+            status_message_tag: 0,
+            status_renderer: None,
+
+            on_select: None,
+
+            delegate: Box::new(DefaultItemDelegate),
+            infinite_scrolling: false,
+
+            type_ahead_enabled: false,
+            type_ahead: String::new(),
+            type_ahead_tag: 0,
+        }
+    }
+}
+
+/// Write the item's `filter_value`, styled by selection and marked in multi-select mode.
+fn render_title(w: &mut dyn Write, model: &Model, index: usize, item: &dyn Item) {
+    let mut style = if index == model.index() {
+        model.selected_item_style.clone()
+    } else {
+        model.normal_item_style.clone()
+    };
+    style.content = item.filter_value();
+
+    if model.multi_select() {
+        let marker = if model.is_selected(item) {
+            "[x] "
+        } else {
+            "[ ] "
+        };
+        let _ = write!(w, "{}", marker);
+    }
+    let _ = write!(w, "{}", style.stylize());
+}
+
+#[derive(Clone)]
+/// The default list item delegate.
+pub struct DefaultItemDelegate;
+
+impl ItemDelegate for DefaultItemDelegate {
+    fn render(&self, w: &mut dyn Write, model: &Model, index: usize, item: &dyn Item) {
+        render_title(w, model, index, item);
+    }
+
+    fn height(&self) -> usize {
+        1
+    }
+
+    fn spacing(&self) -> usize {
+        0
+    }
+
+    fn update(&self, _event: Event, _model: &mut Model) -> Option<Event> {
+        None
+    }
+}
+
+#[derive(Clone)]
+/// A two-line list item delegate.
+///
+/// Renders the item like [`DefaultItemDelegate`], followed by its dimmed
+/// [`Item::description`] on a second line.
+pub struct DescriptionItemDelegate;
+
+impl ItemDelegate for DescriptionItemDelegate {
+    fn render(&self, w: &mut dyn Write, model: &Model, index: usize, item: &dyn Item) {
+        render_title(w, model, index, item);
+        // Items without a description still take two lines to keep pages aligned.
+        let description = item.description().unwrap_or_default();
+        let _ = write!(w, "\n{}", matcha::style(description).dim());
+    }
+
+    fn height(&self) -> usize {
+        2
+    }
+
+    fn spacing(&self) -> usize {
+        0
+    }
+
+    fn update(&self, _event: Event, _model: &mut Model) -> Option<Event> {
+        None
+    }
+}
+
+impl Model {
+    /// Create a new list model with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the item delegate used to render items and handle custom events.
+    pub fn with_delegate(mut self, delegate: impl ItemDelegate + Clone + 'static) -> Self {
+        self.delegate = Box::new(delegate);
+        self.update_pagination();
+        self
+    }
+
+    /// Initialize the list items.
+    pub fn with_items(mut self, items: Vec<Box<dyn Item>>) -> Self {
+        self.items = items.into_iter().map(Arc::from).collect();
+        self.update_pagination();
+        self
+    }
+
+    /// Replace the list items. This also clears the multi-selection.
+    pub fn set_items(&mut self, items: Vec<Box<dyn Item>>) {
+        self.items = items.into_iter().map(Arc::from).collect();
+        self.selected.clear();
+        self.update_pagination();
+    }
+
+    /// Enable/disable checkbox-style selection of several items, toggled with Space.
+    pub fn with_multi_select(mut self, enabled: bool) -> Self {
+        self.multi_select = enabled;
+        self
+    }
+
+    /// Return true if several items can be selected.
+    pub fn multi_select(&self) -> bool {
+        self.multi_select
+    }
+
+    /// Select the item under the cursor, or unselect it if it already is.
+    ///
+    /// Does nothing unless multi-select is enabled.
+    pub fn toggle_selection(&mut self) {
+        if !self.multi_select {
+            return;
+        }
+        let Some(index) = self
+            .selected_item()
+            .and_then(|item| self.position_of(&*item))
+        else {
+            return;
+        };
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+    }
+
+    /// Return the items selected with [`Model::toggle_selection`], in list order.
+    ///
+    /// Items hidden by the current filter are included.
+    pub fn selected_items(&self) -> Vec<Arc<dyn Item>> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.selected.contains(i))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    /// Return true if `item` is one of the [`Model::selected_items`].
+    pub fn is_selected(&self, item: &dyn Item) -> bool {
+        self.position_of(item)
+            .is_some_and(|index| self.selected.contains(&index))
+    }
+
+    fn position_of(&self, item: &dyn Item) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|i| std::ptr::addr_eq(Arc::as_ptr(i), item as *const dyn Item))
+    }
+
+    /// Set the available rendering area `(width, height)` in cells.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.update_pagination();
+    }
+
+    /// Set the list title.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// Show/hide the title line.
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+        self.update_pagination();
+    }
+
+    /// Show/hide the status bar.
+    pub fn set_show_status_bar(&mut self, show: bool) {
+        self.show_status_bar = show;
+        self.update_pagination();
+    }
+
+    /// Show/hide the pagination line.
+    pub fn set_show_pagination(&mut self, show: bool) {
+        self.show_pagination = show;
+        self.update_pagination();
+    }
+
+    /// Set how the pagination line shows the current page.
+    pub fn set_pagination_style(&mut self, style: PaginationStyle) {
+        self.pagination_style = style;
+    }
+
+    /// Show/hide the help line.
+    pub fn set_show_help(&mut self, show: bool) {
+        self.show_help = show;
+        self.update_pagination();
+    }
+
+    /// Render the status bar content with `f` instead of the default item count.
+    ///
+    /// `f` receives the list so it can show e.g. [`Model::index`] or [`Model::filter`].
+    pub fn set_status_renderer(&mut self, f: impl Fn(&Model) -> String + Send + 'static) {
+        self.status_renderer = Some(Box::new(f));
+    }
+
+    /// Also send the message built by `f` whenever the selection changes.
+    ///
+    /// `f` receives the same index as [`ListSelectMsg`] and the selected item, e.g. to
+    /// update a preview pane next to the list.
+    pub fn on_select(&mut self, f: impl Fn(usize, &dyn Item) -> Msg + Send + 'static) {
+        self.on_select = Some(Box::new(f));
+    }
+
+    /// Set the item label used by the status bar (singular/plural).
+    pub fn set_status_bar_item_name(
+        &mut self,
+        singular: impl Into<String>,
+        plural: impl Into<String>,
+    ) {
+        self.item_name_singular = singular.into();
+        self.item_name_plural = plural.into();
+    }
+
+    /// Show `msg` next to the title for `duration`.
+    ///
+    /// Returns the command that clears it again. A newer status message replaces this
+    /// one and keeps showing for its own duration.
+    pub fn new_status_message(&mut self, msg: impl Into<String>, duration: Duration) -> Cmd {
+        self.status_message = msg.into();
+        self.status_message_tag = self.status_message_tag.wrapping_add(1);
+        let tag = self.status_message_tag;
+        matcha::delay(duration, move || Box::new(StatusTimeoutMsg { tag }))
+    }
+
+    /// Start showing the spinner and schedule the first tick.
+    pub fn start_spinner(&mut self) -> Option<Cmd> {
+        self.show_spinner = true;
+        Some(self.spinner.tick(self.spinner.tag()))
+    }
+
+    /// Replace the spinner shown next to the title, e.g. to pick a [`SpinnerType`] and
+    /// color that match the theme. Call [`Model::start_spinner`] again to animate it if
+    /// it was already showing.
+    pub fn set_spinner(&mut self, spinner: Spinner) {
+        self.spinner = spinner;
+    }
+
+    /// Return a mutable reference to the spinner shown next to the title.
+    pub fn spinner_mut(&mut self) -> &mut Spinner {
+        &mut self.spinner
+    }
+
+    /// Stop showing the spinner.
+    pub fn stop_spinner(&mut self) {
+        self.show_spinner = false;
+    }
+
+    /// Toggle the spinner. Returns a command if enabling.
+    pub fn toggle_spinner(&mut self) -> Option<Cmd> {
+        if !self.show_spinner {
+            self.start_spinner()
+        } else {
+            self.stop_spinner();
+            None
+        }
+    }
+
+    /// Return all currently visible items.
+    ///
+    /// When a filter is set, only items whose `filter_value` matches the query
+    /// (case-insensitive) are returned. In [`FilterMode::Fuzzy`] they are sorted by
+    /// score, best first; ties keep their original order.
+    pub fn visible_items(&self) -> Vec<Arc<dyn Item>> {
+        if self.filter.is_empty() {
+            return self.items.clone();
+        }
+        match self.filter_mode {
+            FilterMode::Substring => {
+                let query = self.filter.to_lowercase();
+                self.items
+                    .iter()
+                    .filter(|item| item.filter_value().to_lowercase().contains(&query))
+                    .cloned()
+                    .collect()
+            }
+            FilterMode::Fuzzy => {
+                let mut matches: Vec<(i64, &Arc<dyn Item>)> = self
+                    .items
+                    .iter()
+                    .filter_map(|item| {
+                        fuzzy_match(&self.filter, &item.filter_value()).map(|m| (m.score, item))
+                    })
+                    .collect();
+                matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+                matches.into_iter().map(|(_, item)| item.clone()).collect()
+            }
+        }
+    }
+
+    /// Return the char indices of `item`'s `filter_value` matched by the current filter,
+    /// e.g. to highlight them in a delegate. Empty when there is no filter or no match.
+    pub fn matched_indices(&self, item: &dyn Item) -> Vec<usize> {
+        if self.filter.is_empty() {
+            return vec![];
+        }
+        let value: Vec<char> = item.filter_value().chars().collect();
+        match self.filter_mode {
+            FilterMode::Substring => {
+                let query: Vec<char> = self.filter.chars().collect();
+                value
+                    .windows(query.len())
+                    .position(|window| {
+                        window
+                            .iter()
+                            .zip(&query)
+                            .all(|(&c, &q)| eq_ignore_case(c, q))
+                    })
+                    .map(|start| (start..start + query.len()).collect())
+                    .unwrap_or_default()
+            }
+            FilterMode::Fuzzy => {
+                let value: String = value.into_iter().collect();
+                fuzzy_match(&self.filter, &value)
+                    .map(|m| m.indices)
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// Return how the filter query is matched.
+    pub fn filter_mode(&self) -> FilterMode {
+        self.filter_mode
+    }
+
+    /// Set how the filter query is matched. The current query is applied again.
+    pub fn set_filter_mode(&mut self, mode: FilterMode) {
+        self.filter_mode = mode;
+        let query = std::mem::take(&mut self.filter);
+        self.set_filter(query);
+    }
+
+    /// Return the current filter query.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Set the filter query.
+    ///
+    /// The cursor stays on the selected item if it is still visible. Otherwise it is
+    /// clamped into the filtered range, and the original item is selected again once
+    /// it becomes visible (e.g. when the filter is cleared).
+    pub fn set_filter(&mut self, query: impl Into<String>) {
+        let current = self.selected_item().map(|item| item.filter_value());
+        if current != self.fallback_selection {
+            // The user moved away from the clamped item, so it is the new preference.
+            self.preferred_selection = current;
+        }
+
+        self.filter = query.into();
+        self.update_pagination();
+
+        let preferred = self.preferred_selection.as_ref().and_then(|value| {
+            self.visible_items()
+                .iter()
+                .position(|item| &item.filter_value() == value)
+        });
+        match preferred {
+            Some(index) => {
+                self.select_visible(index);
+                self.fallback_selection = None;
+            }
+            None => {
+                self.fallback_selection = self.selected_item().map(|item| item.filter_value());
+            }
+        }
+    }
+
+    /// Clear the filter query.
+    pub fn reset_filter(&mut self) {
+        self.set_filter(String::new());
+    }
+
+    /// Return true while the user is typing a filter query.
+    pub fn filtering(&self) -> bool {
+        self.filtering
+    }
+
+    /// Start typing a filter query, as if `/` was pressed.
+    ///
+    /// The input starts with the current query. Returns the cursor blink command.
+    pub fn start_filtering(&mut self) -> Option<Cmd> {
+        let input = std::mem::take(&mut self.filter_input)
+            .set_value(self.filter.clone())
+            .cursor_end();
+        let (input, cmd) = input.focus();
+        self.filter_input = input;
+        self.filtering = true;
+        cmd
+    }
+
+    /// Stop typing a filter query. The query stays applied unless `clear` is true.
+    pub fn stop_filtering(&mut self, clear: bool) {
+        self.filtering = false;
+        self.filter_input = TextInput::new();
+        if clear {
+            self.reset_filter();
+        }
+    }
+
+    fn handle_filter_key(&mut self, key: &KeyEvent) -> Option<Cmd> {
+        match key.code {
+            KeyCode::Esc => {
+                self.stop_filtering(true);
+                None
+            }
+            KeyCode::Enter => {
+                self.stop_filtering(false);
+                None
+            }
+            KeyCode::Up => {
+                self.cursor_up();
+                None
+            }
+            KeyCode::Down => {
+                self.cursor_down();
+                None
+            }
+            _ => {
+                let msg: Msg = Box::new(*key);
+                let (input, cmd) = std::mem::take(&mut self.filter_input).update(&msg);
+                let query = input.value().to_string();
+                self.filter_input = input;
+                if query != self.filter {
+                    self.set_filter(query);
+                }
+                cmd
+            }
+        }
+    }
+
+    /// Get the currently selected item (if any).
+    pub fn selected_item(&self) -> Option<Arc<dyn Item>> {
+        let i = self.index();
+        let items = self.visible_items();
+
+        if i >= items.len() {
+            return None;
+        }
+
+        Some(items[i].clone())
+    }
+
+    /// Return the absolute index of the selected item in the item list.
+    pub fn index(&self) -> usize {
+        self.page * self.per_page + self.cursor
+    }
+
+    /// Select the visible item at `index`, e.g. to restore the selection on startup.
+    ///
+    /// The page and cursor are derived from `index`, which is clamped to the last
+    /// visible item.
+    pub fn select(&mut self, index: usize) {
+        let len = self.visible_items().len();
+        self.select_visible(index.min(len.saturating_sub(1)));
+    }
+
+    /// Move the cursor to row `cursor` of the current page, clamped to the items on it.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.items_on_page().saturating_sub(1));
+    }
+
+    /// Show page `page`, clamped to the last page. The cursor is kept on the same row
+    /// if the page has enough items.
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page.min(self.total_pages.saturating_sub(1));
+        let cursor = self.cursor;
+        self.set_cursor(cursor);
+    }
+
+    /// Move selection up.
+    pub fn cursor_up(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            return;
+        }
+
+        // We're at the top of the page
+        if self.page > 0 {
+            self.page -= 1;
+            self.cursor = self.per_page - 1;
+        } else if self.infinite_scrolling {
+            // Go to the last page
+            self.page = self.total_pages - 1;
+            let items_on_page = self.items_on_page();
+            self.cursor = if items_on_page > 0 {
+                items_on_page - 1
+            } else {
+                0
+            };
+        }
+    }
+
+    /// Move selection down.
+    pub fn cursor_down(&mut self) {
+        let items_on_page = self.items_on_page();
+
+        if self.cursor + 1 < items_on_page {
+            self.cursor += 1;
+            return;
+        }
+
+        // We're at the bottom of the page
+        if self.page + 1 < self.total_pages {
+            self.page += 1;
+            self.cursor = 0;
+        } else if self.infinite_scrolling {
+            // Go to the first page
+            self.page = 0;
+            self.cursor = 0;
+        }
+    }
+
+    /// Move to previous page.
+    pub fn prev_page(&mut self) {
+        if self.page > 0 {
+            self.page -= 1;
+            // Make sure cursor is within bounds
+            let items_on_page = self.items_on_page();
+            if self.cursor >= items_on_page && items_on_page > 0 {
+                self.cursor = items_on_page - 1;
+            }
+        }
+    }
+
+    /// Move to next page.
+    pub fn next_page(&mut self) {
+        if self.page + 1 < self.total_pages {
+            self.page += 1;
+            // Make sure cursor is within bounds
+            let items_on_page = self.items_on_page();
+            if self.cursor >= items_on_page && items_on_page > 0 {
+                self.cursor = items_on_page - 1;
+            }
+        }
+    }
+
+    /// Jump to the first item.
+    pub fn go_to_start(&mut self) {
+        self.page = 0;
+        self.cursor = 0;
+    }
+
+    /// Jump to the last item.
+    pub fn go_to_end(&mut self) {
+        self.page = self.total_pages - 1;
+        let items_on_page = self.items_on_page();
+        self.cursor = if items_on_page > 0 {
+            items_on_page - 1
+        } else {
+            0
+        };
+    }
+
+    fn select_visible(&mut self, index: usize) {
+        self.page = index / self.per_page;
+        self.cursor = index % self.per_page;
+    }
+
+    fn update_pagination(&mut self) {
+        let mut available_height = self.height;
+
+        // Adjust for title
+        if self.show_title {
+            available_height = available_height.saturating_sub(1);
+        }
+
+        // Adjust for status bar
+        if self.show_status_bar {
+            available_height = available_height.saturating_sub(1);
+        }
+
+        // Adjust for pagination
+        if self.show_pagination {
+            available_height = available_height.saturating_sub(1);
+        }
+
+        // Adjust for help
+        if self.show_help {
+            available_height = available_height.saturating_sub(1);
+        }
+
+        // Calculate per_page
+        let item_height = self.delegate.height() + self.delegate.spacing();
+        self.per_page = if item_height > 0 {
+            std::cmp::max(1, available_height / item_height)
+        } else {
+            1
+        };
+
+        // Calculate total_pages
+        let total_items = self.visible_items().len();
+        self.total_pages = if total_items == 0 {
+            1
+        } else {
+            total_items.div_ceil(self.per_page)
+        };
+
+        // Ensure page is in bounds
+        self.page = std::cmp::min(self.page, self.total_pages.saturating_sub(1));
+
+        // Ensure cursor is in bounds
+        let items_on_page = self.items_on_page();
+        if self.cursor >= items_on_page && items_on_page > 0 {
+            self.cursor = items_on_page - 1;
+        }
+    }
+
+    fn items_on_page(&self) -> usize {
+        let total_items = self.visible_items().len();
+        if total_items == 0 {
+            return 0;
+        }
+
+        let remaining = total_items - (self.page * self.per_page);
+        std::cmp::min(remaining, self.per_page)
+    }
+
+    fn handle_key_event(&mut self, key: &KeyEvent) -> Option<Cmd> {
+        if self.filtering {
+            return self.handle_filter_key(key);
+        }
+        match key.code {
+            KeyCode::Char('/') => {
+                return self.start_filtering();
+            }
+            KeyCode::Esc if !self.filter.is_empty() => {
+                self.reset_filter();
+            }
+            KeyCode::Char(' ') if self.multi_select => {
+                self.toggle_selection();
+            }
+            KeyCode::Char(c)
+                if self.type_ahead_enabled
+                    && (key.modifiers - matcha::KeyModifiers::SHIFT).is_empty() =>
+            {
+                return Some(self.type_ahead(c));
+            }
+            KeyCode::Up => {
+                self.cursor_up();
+            }
+            KeyCode::Down => {
+                self.cursor_down();
+            }
+            KeyCode::PageUp => {
+                self.prev_page();
+            }
+            KeyCode::PageDown => {
+                self.next_page();
+            }
+            KeyCode::Home => {
+                self.go_to_start();
+            }
+            KeyCode::End => {
+                self.go_to_end();
+            }
+            _ => {
+                // Let the delegate handle other keys (e.g. Enter).
+                // This prevents examples from panicking on unhandled input and allows
+                // custom delegates to emit events back to the app.
+                // We temporarily move the delegate out to avoid borrowing `self` both
+                // immutably (for `delegate`) and mutably (for the `model` argument).
+                let delegate = std::mem::replace(&mut self.delegate, Box::new(DefaultItemDelegate));
+                let event = delegate.update(Event::Key(*key), self);
+                self.delegate = delegate;
+
+                if let Some(event) = event {
+                    return Some(Cmd::sync(Box::new(move || Box::new(event) as Msg)));
+                }
+            }
+        }
+        None
+    }
+
+    fn title_view(&self) -> String {
+        if !self.show_title {
+            return String::new();
+        }
+
+        if self.filtering {
+            return self.filter_input.view().to_string();
+        }
+
+        let mut view = String::new();
+
+        // Show spinner if enabled
+        if self.show_spinner {
+            view.push_str(&self.spinner.view().to_string());
+            view.push(' ');
+        }
+
+        let mut title_style = self.title_style.clone();
+        title_style.content = self.title.clone();
+        view.push_str(&title_style.content);
+
+        // Add status message if there is one
+        if !self.status_message.is_empty() {
+            view.push_str("  ");
+            view.push_str(&self.status_message);
+        }
+
+        view
+    }
+
+    fn status_view(&self) -> String {
+        if !self.show_status_bar {
+            return String::new();
+        }
+
+        if let Some(renderer) = &self.status_renderer {
+            let mut status_style = self.status_bar_style.clone();
+            status_style.content = renderer(self);
+            return status_style.content;
+        }
+
+        let total_items = self.items.len();
+        let visible_items = self.visible_items().len();
+
+        let mut status = String::new();
+
+        // Determine item name
+        let item_name = if visible_items == 1 {
+            &self.item_name_singular
+        } else {
+            &self.item_name_plural
+        };
+
+        // Show count
+        if total_items == 0 {
+            status.push_str(&format!("No {}", self.item_name_plural));
+        } else if !self.filter.is_empty() {
+            status.push_str(&format!("{}/{} {}", visible_items, total_items, item_name));
+        } else {
+            status.push_str(&format!("{} {}", visible_items, item_name));
+        }
+
+        let mut status_style = self.status_bar_style.clone();
+        status_style.content = status;
+        status_style.content
+    }
+
+    fn pagination_view(&self) -> String {
+        if !self.show_pagination || self.total_pages <= 1 {
+            return String::new();
+        }
+
+        // Dots are separated by a space, so they take two cells per page.
+        if self.pagination_style == PaginationStyle::Dots && self.total_pages * 2 - 1 <= self.width
+        {
+            return (0..self.total_pages)
+                .map(|page| if page == self.page { "●" } else { "○" })
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+
+        let current_page = self.page + 1;
+        format!("{}/{}", current_page, self.total_pages)
+    }
+
+    fn help_view(&self) -> String {
+        if !self.show_help {
+            return String::new();
+        }
+
+        matcha::help_line([
+            ("Navigate", vec![Key(key!(up)), Key(key!(down))]),
+            ("Filter", vec![Key(key!('/'))]),
+            ("Quit", vec![Key(key!(q))]),
+        ])
+    }
+
+    fn items_view<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        let items = self.visible_items();
+
+        if items.is_empty() {
+            return write!(w, "No {}.", self.item_name_plural);
+        }
+
+        let start = self.page * self.per_page;
+        let end = std::cmp::min(start + self.per_page, items.len());
+
+        if start >= items.len() {
+            return Ok(());
+        }
+
+        for (i, item) in items.iter().enumerate().take(end).skip(start) {
+            if i > start {
+                // Add spacing
+                for _ in 0..self.delegate.spacing() {
+                    writeln!(w)?;
+                }
+                // Add newline for the next item
+                writeln!(w)?;
+            }
+
+            self.delegate.render(w, self, i, &**item);
+        }
+
+        Ok(())
+    }
+
+    /// Rank items with [`fuzzy_match`] instead of substring matching, see
+    /// [`FilterMode::Fuzzy`].
+    pub fn with_fuzzy_filter(mut self, enabled: bool) -> Self {
+        self.set_filter_mode(if enabled {
+            FilterMode::Fuzzy
+        } else {
+            FilterMode::Substring
+        });
+        self
+    }
+
+    /// Enable/disable infinite scrolling for cursor navigation.
+    pub fn with_infinite_scrolling(mut self, enabled: bool) -> Self {
+        self.infinite_scrolling = enabled;
+        self
+    }
+
+    /// Enable/disable jumping to items by typing the start of their `filter_value`.
+    ///
+    /// Typed characters build up a prefix that is reset after a second without
+    /// typing; the cursor moves to the next visible item starting with it
+    /// (case-insensitive). Keys the list handles itself, such as `/` and Space in
+    /// multi-select mode, keep their meaning. When enabled, other plain characters are
+    /// no longer forwarded to the delegate.
+    pub fn with_type_ahead(mut self, enabled: bool) -> Self {
+        self.type_ahead_enabled = enabled;
+        self
+    }
+
+    fn type_ahead(&mut self, c: char) -> Cmd {
+        // A fresh prefix looks past the current item so repeating a letter cycles
+        // through the items starting with it; a longer prefix may stay put.
+        let skip = if self.type_ahead.is_empty() { 1 } else { 0 };
+        self.type_ahead.push(c);
+        let prefix = self.type_ahead.to_lowercase();
+
+        let items = self.visible_items();
+        let found = (0..items.len())
+            .map(|offset| (self.index() + skip + offset) % items.len())
+            .find(|&i| items[i].filter_value().to_lowercase().starts_with(&prefix));
+        if let Some(index) = found {
+            self.select_visible(index);
+        }
+
+        self.type_ahead_tag = self.type_ahead_tag.wrapping_add(1);
+        let tag = self.type_ahead_tag;
+        matcha::delay(TYPE_AHEAD_TIMEOUT, move || {
+            Box::new(TypeAheadResetMsg { tag })
+        })
+    }
+
+    /// Update the list using an external event.
+    ///
+    /// Note: currently a placeholder. Keyboard events are handled by the `matcha::Model` impl.
+    pub fn update(&mut self, _event: Event) -> Option<Cmd> {
+        None
+    }
+}
+
+impl Model {
+    /// Render the list into `w`.
+    pub fn render<W: Write>(&self, w: &mut W) -> Result<(), std::fmt::Error> {
+        // Render title
+        if self.show_title {
+            writeln!(w, "{}", self.title_view())?;
+        }
+
+        // Render status
+        if self.show_status_bar {
+            writeln!(w, "{}", self.status_view())?;
+        }
+
+        // Render items
+        self.items_view(w)?;
+
+        // Render pagination
+        if self.show_pagination && self.total_pages > 1 {
+            writeln!(w, "\n{}", self.pagination_view())?;
+        }
+
+        // Render help
+        if self.show_help {
+            writeln!(w, "\n{}", self.help_view())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MModel for Model {
+    fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+        (
+            Self {
+                width: input.size.0 as usize,
+                height: input.size.1 as usize,
+                ..self
+            },
+            None,
+        )
+    }
+
+    fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+        if let Some(key_event) = msg.downcast_ref::<KeyEvent>() {
+            let mut new_self = self;
+            let old_index = new_self.index();
+            let old_item = new_self.selected_item();
+            let mut cmds: Vec<Cmd> = new_self.handle_key_event(key_event).into_iter().collect();
+
+            let item = new_self.selected_item();
+            let same_item = match (&old_item, &item) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            };
+            if let Some(item) = item.filter(|_| old_index != new_self.index() || !same_item) {
+                let index = new_self.index();
+                cmds.push(Cmd::sync(Box::new(move || {
+                    Box::new(ListSelectMsg { index })
+                })));
+                if let Some(on_select) = &new_self.on_select {
+                    let m = on_select(index, &*item);
+                    cmds.push(Cmd::sync(Box::new(move || m)));
+                }
+            }
+            return (new_self, matcha::cmds(cmds));
+        }
+
+        let mut new_self = self;
+        let mut cmds = vec![];
+
+        if let Some(reset) = msg.downcast_ref::<TypeAheadResetMsg>() {
+            if reset.tag == new_self.type_ahead_tag {
+                new_self.type_ahead.clear();
+            }
+        }
+
+        if let Some(timeout) = msg.downcast_ref::<StatusTimeoutMsg>() {
+            if timeout.tag == new_self.status_message_tag {
+                new_self.status_message.clear();
+            }
+        }
+
+        // Keep the filter input's cursor blinking
+        if new_self.filtering {
+            let (input, cmd) = std::mem::take(&mut new_self.filter_input).update(msg);
+            new_self.filter_input = input;
+            cmds.extend(cmd);
+        }
+
+        // Handle spinner tick messages
+        if msg.downcast_ref::<TickMsg>().is_some() {
+            let (new_spinner, cmd) = new_self.spinner.update(msg);
+            new_self = Self {
+                spinner: new_spinner,
+                ..new_self
+            };
+            cmds.extend(cmd);
+        }
+
+        (new_self, matcha::cmds(cmds))
+    }
+
+    fn view(&self) -> impl Display {
+        let mut output = String::new();
+        let _ = self.render(&mut output);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestItem(&'static str);
+
+    impl Item for TestItem {
+        fn filter_value(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    fn list_of(values: &[&'static str]) -> Model {
+        let items = values
+            .iter()
+            .map(|v| Box::new(TestItem(v)) as Box<dyn Item>)
+            .collect();
+        Model::new().with_items(items)
+    }
+
+    struct DescribedItem(&'static str, &'static str);
+
+    impl Item for DescribedItem {
+        fn filter_value(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn description(&self) -> Option<String> {
+            Some(self.1.to_string())
+        }
+    }
+
+    fn selected_value(list: &Model) -> Option<String> {
+        list.selected_item().map(|item| item.filter_value())
+    }
+
+    #[test]
+    fn status_renderer_replaces_item_count() {
+        let mut list = list_of(&["apple", "banana", "cherry"]);
+        list.set_status_renderer(|list| {
+            format!("{} of {}", list.index() + 1, list.visible_items().len())
+        });
+        list.cursor_down();
+
+        let view = list.view().to_string();
+        assert!(view.contains("2 of 3"));
+        assert!(!view.contains("3 items"));
+    }
+
+    #[test]
+    fn filter_keeps_selection_on_same_item() {
+        let mut list = list_of(&["apple", "banana", "blueberry", "cherry"]);
+        list.cursor_down();
+        list.cursor_down();
+
+        list.set_filter("b");
+
+        assert_eq!(list.index(), 1);
+        assert_eq!(selected_value(&list).as_deref(), Some("blueberry"));
+    }
+
+    #[test]
+    fn filter_smaller_than_index_clamps_cursor() {
+        let mut list = list_of(&["apple", "banana", "blueberry", "cherry"]);
+        list.go_to_end();
+
+        list.set_filter("an");
+
+        assert_eq!(list.visible_items().len(), 1);
+        assert_eq!(list.index(), 0);
+        assert_eq!(selected_value(&list).as_deref(), Some("banana"));
+    }
+
+    #[test]
+    fn clearing_filter_restores_previous_selection() {
+        let mut list = list_of(&["apple", "banana", "blueberry", "cherry"]);
+        list.go_to_end();
+
+        list.set_filter("an");
+        list.reset_filter();
+
+        assert_eq!(list.index(), 3);
+        assert_eq!(selected_value(&list).as_deref(), Some("cherry"));
+    }
+
+    #[test]
+    fn moving_while_filtered_changes_restored_selection() {
+        let mut list = list_of(&["apple", "banana", "blueberry", "cherry"]);
+        list.go_to_end();
+
+        list.set_filter("b");
+        list.cursor_up();
+        list.reset_filter();
+
+        assert_eq!(selected_value(&list).as_deref(), Some("banana"));
+    }
+
+    #[test]
+    fn fuzzy_mode_matches_subsequences() {
+        let mut list = list_of(&["Nutella", "Nougat"]);
+        list.set_filter("ntl");
+        assert!(list.visible_items().is_empty());
+
+        list.set_filter_mode(FilterMode::Fuzzy);
+        assert_eq!(list.visible_items().len(), 1);
+        assert_eq!(selected_value(&list).as_deref(), Some("Nutella"));
+        assert_eq!(list.matched_indices(&TestItem("Nutella")), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn fuzzy_mode_sorts_best_matches_first() {
+        let mut list = list_of(&["a_b_c", "abc", "xabc"]).with_fuzzy_filter(true);
+        list.set_filter("abc");
+
+        let values: Vec<String> = list
+            .visible_items()
+            .iter()
+            .map(|item| item.filter_value())
+            .collect();
+        assert_eq!(values, vec!["abc", "xabc", "a_b_c"]);
+    }
+
+    #[test]
+    fn substring_matched_indices_ignore_case() {
+        let mut list = list_of(&["Banana"]);
+        list.set_filter("NAN");
+        assert_eq!(list.matched_indices(&TestItem("Banana")), vec![2, 3, 4]);
+    }
+
+    fn press(list: Model, code: KeyCode) -> Model {
+        let msg: Msg = Box::new(KeyEvent::new(code, matcha::KeyModifiers::NONE));
+        let (list, _) = MModel::update(list, &msg);
+        list
+    }
+
+    #[derive(Clone)]
+    struct EchoDelegate;
+
+    impl ItemDelegate for EchoDelegate {
+        fn render(&self, _w: &mut dyn Write, _model: &Model, _index: usize, _item: &dyn Item) {}
+
+        fn height(&self) -> usize {
+            1
+        }
+
+        fn spacing(&self) -> usize {
+            0
+        }
+
+        fn update(&self, event: Event, _model: &mut Model) -> Option<Event> {
+            Some(event)
+        }
+    }
+
+    #[test]
+    fn unhandled_char_key_does_not_panic() {
+        let list = list_of(&["apple", "banana"]);
+        let msg: Msg = Box::new(KeyEvent::new(
+            KeyCode::Char('x'),
+            matcha::KeyModifiers::NONE,
+        ));
+        let (list, cmd) = MModel::update(list, &msg);
+        assert!(cmd.is_none());
+        assert_eq!(selected_value(&list).as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn unhandled_key_is_forwarded_to_delegate() {
+        let list = list_of(&["apple"]).with_delegate(EchoDelegate);
+        let msg: Msg = Box::new(KeyEvent::new(KeyCode::Enter, matcha::KeyModifiers::NONE));
+        let (_, cmd) = MModel::update(list, &msg);
+
+        let Some(Cmd::Sync(matcha::SyncCmd(cmd))) = cmd else {
+            panic!("the delegate's event should be returned as a command");
+        };
+        let event = cmd();
+        assert!(matches!(
+            event.downcast_ref::<Event>(),
+            Some(Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            }))
+        ));
+    }
+
+    fn sync_msgs(cmd: Option<Cmd>) -> Vec<Msg> {
+        match cmd {
+            None => vec![],
+            Some(Cmd::Sync(matcha::SyncCmd(f))) => {
+                let msg = f();
+                match msg.downcast::<matcha::BatchMsg>() {
+                    Ok(batch) => batch.into_iter().flat_map(|c| sync_msgs(Some(c))).collect(),
+                    Err(msg) => match msg.downcast::<matcha::DelayMsg>() {
+                        // Timers fire right away in tests.
+                        Ok(delay) => sync_msgs(Some(delay.1)),
+                        Err(msg) => vec![msg],
+                    },
+                }
+            }
+            Some(Cmd::Async(_)) => panic!("unexpected async command"),
+        }
+    }
+
+    #[test]
+    fn moving_the_cursor_emits_selection_messages() {
+        let mut list = list_of(&["apple", "banana"]);
+        list.on_select(|index, item| Box::new(format!("{index}:{}", item.filter_value())));
+
+        let down: Msg = Box::new(KeyEvent::new(KeyCode::Down, matcha::KeyModifiers::NONE));
+        let (list, cmd) = MModel::update(list, &down);
+        let msgs = sync_msgs(cmd);
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(msgs[0].downcast_ref::<ListSelectMsg>().unwrap().index, 1);
+        assert_eq!(msgs[1].downcast_ref::<String>().unwrap(), "1:banana");
+
+        // Already on the last item: nothing changes, nothing is sent.
+        let (_, cmd) = MModel::update(list, &down);
+        assert!(sync_msgs(cmd).is_empty());
+    }
+
+    #[test]
+    fn status_message_expires_unless_superseded() {
+        let mut list = list_of(&["apple"]);
+        let first = sync_msgs(Some(list.new_status_message("saved", Duration::ZERO)));
+        let second = sync_msgs(Some(list.new_status_message("deleted", Duration::ZERO)));
+        assert!(list.view().to_string().contains("deleted"));
+
+        let (list, _) = MModel::update(list, &first.into_iter().next().unwrap());
+        assert!(list.view().to_string().contains("deleted"));
+
+        let (list, _) = MModel::update(list, &second.into_iter().next().unwrap());
+        assert!(!list.view().to_string().contains("deleted"));
+    }
+
+    #[test]
+    fn replaced_spinner_animates() {
+        let mut list = list_of(&["apple"]);
+        list.set_spinner(Spinner::new(SpinnerType::dot()).set_color(MatchaColor::Magenta));
+
+        let tick = sync_msgs(list.start_spinner()).pop().unwrap();
+        let (list, cmd) = MModel::update(list, &tick);
+        assert!(cmd.is_some());
+        assert_eq!(list.spinner.frame(), 1);
+        assert_eq!(list.spinner.color(), Some(MatchaColor::Magenta));
+    }
+
+    #[test]
+    fn space_toggles_items_in_multi_select_mode() {
+        let list = list_of(&["apple", "banana", "cherry"]).with_multi_select(true);
+        let list = press(list, KeyCode::Char(' '));
+        let list = press(list, KeyCode::Down);
+        let list = press(list, KeyCode::Down);
+        let list = press(list, KeyCode::Char(' '));
+
+        let values: Vec<String> = list
+            .selected_items()
+            .iter()
+            .map(|item| item.filter_value())
+            .collect();
+        assert_eq!(values, vec!["apple", "cherry"]);
+
+        let list = press(list, KeyCode::Char(' '));
+        assert_eq!(list.selected_items().len(), 1);
+    }
+
+    #[test]
+    fn selection_is_kept_for_items_hidden_by_filter() {
+        let mut list = list_of(&["apple", "banana"]).with_multi_select(true);
+        list.cursor_down();
+        list.toggle_selection();
+        list.set_filter("app");
+        assert!(!list.is_selected(&*list.selected_item().unwrap()));
+        assert_eq!(list.selected_items().len(), 1);
+
+        let view = list.view().to_string();
+        assert!(view.contains("[ ] "));
+        list.reset_filter();
+        assert!(list.view().to_string().contains("[x] "));
+    }
+
+    #[test]
+    fn dots_pagination_falls_back_to_arabic_when_too_wide() {
+        let mut list = list_of(&["a", "b", "c"]);
+        list.set_pagination_style(PaginationStyle::Dots);
+        list.set_show_title(false);
+        list.set_show_status_bar(false);
+        list.set_show_help(false);
+        list.set_size(5, 2);
+        list.next_page();
+        assert_eq!(list.pagination_view(), "○ ● ○");
+
+        list.set_size(4, 2);
+        assert_eq!(list.pagination_view(), "2/3");
+    }
+
+    #[test]
+    fn type_ahead_jumps_to_prefix_and_resets_after_pause() {
+        let list = list_of(&["apple", "banana", "blueberry", "cherry"]).with_type_ahead(true);
+        let list = press(list, KeyCode::Char('b'));
+        assert_eq!(selected_value(&list).as_deref(), Some("banana"));
+        let list = press(list, KeyCode::Char('l'));
+        assert_eq!(selected_value(&list).as_deref(), Some("blueberry"));
+
+        let reset: Msg = Box::new(TypeAheadResetMsg {
+            tag: list.type_ahead_tag,
+        });
+        let (list, _) = MModel::update(list, &reset);
+        let list = press(list, KeyCode::Char('a'));
+        assert_eq!(selected_value(&list).as_deref(), Some("apple"));
+        let list = press(list, KeyCode::Down);
+        assert_eq!(selected_value(&list).as_deref(), Some("banana"));
+    }
+
+    #[test]
+    fn type_ahead_resets_only_after_the_last_key() {
+        let list = list_of(&["apple", "banana", "blueberry"]).with_type_ahead(true);
+        let key =
+            |c| -> Msg { Box::new(KeyEvent::new(KeyCode::Char(c), matcha::KeyModifiers::NONE)) };
+        let reset = |cmd| {
+            sync_msgs(cmd)
+                .into_iter()
+                .find(|msg| msg.is::<TypeAheadResetMsg>())
+                .unwrap()
+        };
+        let (list, first) = MModel::update(list, &key('b'));
+        let (list, second) = MModel::update(list, &key('l'));
+
+        let (list, _) = MModel::update(list, &reset(first));
+        assert_eq!(list.type_ahead, "bl");
+
+        let (list, _) = MModel::update(list, &reset(second));
+        assert!(list.type_ahead.is_empty());
+    }
+
+    #[test]
+    fn select_and_page_setters_clamp_to_bounds() {
+        let mut list = list_of(&["a", "b", "c", "d", "e"]);
+        list.set_size(80, 6);
+        assert_eq!(list.per_page, 2);
+
+        list.select(3);
+        assert_eq!((list.page, list.cursor, list.index()), (1, 1, 3));
+
+        list.set_page(2);
+        assert_eq!((list.page, list.cursor), (2, 0));
+        list.set_page(9);
+        assert_eq!(list.page, 2);
+
+        list.set_page(0);
+        list.set_cursor(5);
+        assert_eq!(list.index(), 1);
+
+        // The remembered index is on a page that disappears when the list shrinks.
+        list.set_items(vec![Box::new(TestItem("x")), Box::new(TestItem("y"))]);
+        list.select(4);
+        assert_eq!((list.page, list.cursor), (0, 1));
+        assert_eq!(selected_value(&list).as_deref(), Some("y"));
+    }
+
+    #[test]
+    fn slash_starts_filtering_and_typing_narrows_items() {
+        let list = list_of(&["apple", "banana", "blueberry"]);
+        let list = press(list, KeyCode::Char('/'));
+        assert!(list.filtering());
+
+        let list = press(list, KeyCode::Char('b'));
+        let list = press(list, KeyCode::Char('l'));
+
+        assert_eq!(list.filter(), "bl");
+        assert_eq!(selected_value(&list).as_deref(), Some("blueberry"));
+        assert!(list.view().to_string().contains("1/3 item"));
+    }
+
+    #[test]
+    fn enter_keeps_filter_and_esc_cancels_it() {
+        let list = list_of(&["apple", "banana", "blueberry"]);
+        let list = press(list, KeyCode::Char('/'));
+        let list = press(list, KeyCode::Char('b'));
+        let list = press(list, KeyCode::Enter);
+        assert!(!list.filtering());
+        assert_eq!(list.visible_items().len(), 2);
+
+        let list = press(list, KeyCode::Char('/'));
+        let list = press(list, KeyCode::Esc);
+        assert!(!list.filtering());
+        assert_eq!(list.filter(), "");
+        assert_eq!(list.visible_items().len(), 3);
+    }
+
+    #[test]
+    fn description_renders_dimmed_on_second_line() {
+        let delegate = DescriptionItemDelegate;
+        let list = Model::new()
+            .with_delegate(delegate.clone())
+            .with_items(vec![Box::new(DescribedItem("apple", "a red fruit"))]);
+
+        let mut out = String::new();
+        delegate.render(&mut out, &list, 1, &DescribedItem("apple", "a red fruit"));
+
+        let lines: Vec<&str> = out.split('\n').collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "apple");
+        assert_eq!(lines[1], matcha::style("a red fruit").dim().to_string());
+        assert_eq!(delegate.height(), 2);
+    }
+
+    #[test]
+    fn default_delegate_renders_one_line() {
+        let list = Model::new()
+            .with_delegate(DefaultItemDelegate)
+            .with_items(vec![Box::new(DescribedItem("apple", "a red fruit"))]);
+        assert_eq!(list.delegate.height(), 1);
+
+        let mut out = String::new();
+        DefaultItemDelegate.render(&mut out, &list, 1, &DescribedItem("apple", "a red fruit"));
+        assert_eq!(out, "apple");
+    }
+}
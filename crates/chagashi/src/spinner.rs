@@ -3,6 +3,7 @@ use matcha::*;
 use std::fmt::Display;
 #[cfg(not(test))]
 use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
 #[cfg(not(test))]
 static ID: AtomicUsize = AtomicUsize::new(1);
 
@@ -227,23 +228,35 @@ impl SpinnerType {
         }
     }
 
-    fn frames(&self) -> &[&'static str] {
+    fn frame(&self, index: usize) -> &'static str {
         match self {
-            Self::Line { frames, .. } => frames,
-            Self::Dot { frames, .. } => frames,
-            Self::MiniDot { frames, .. } => frames,
-            Self::Jump { frames, .. } => frames,
-            Self::Pulse { frames, .. } => frames,
-            Self::Points { frames, .. } => frames,
-            Self::Globe { frames, .. } => frames,
-            Self::Moon { frames, .. } => frames,
-            Self::Monkey { frames, .. } => frames,
-            Self::Meter { frames, .. } => frames,
-            Self::Hamburger { frames, .. } => frames,
+            Self::Line { frames, .. } => frames[index],
+            Self::Dot { frames, .. } => frames[index],
+            Self::MiniDot { frames, .. } => frames[index],
+            Self::Jump { frames, .. } => frames[index],
+            Self::Pulse { frames, .. } => frames[index],
+            Self::Points { frames, .. } => frames[index],
+            Self::Globe { frames, .. } => frames[index],
+            Self::Moon { frames, .. } => frames[index],
+            Self::Monkey { frames, .. } => frames[index],
+            Self::Meter { frames, .. } => frames[index],
+            Self::Hamburger { frames, .. } => frames[index],
         }
     }
 }
 
+/// The order in which a [`Spinner`] plays its frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpinnerDirection {
+    /// First to last, then start over.
+    #[default]
+    Forward,
+    /// Last to first, then start over.
+    Reverse,
+    /// Back and forth between the first and the last frame.
+    PingPong,
+}
+
 /// Model contains the state for the spinner. Use New to create new models
 /// rather than using Model as a struct literal.
 pub struct Spinner {
@@ -253,6 +266,13 @@ pub struct Spinner {
     id: usize,
     tag: usize,
     color: Option<Color>,
+    // Overrides the spinner type's frame interval when set.
+    fps: Option<std::time::Duration>,
+    direction: SpinnerDirection,
+    // Whether a `PingPong` spinner is currently heading towards the first frame.
+    backward: bool,
+    // Frames and interval from `Spinner::with_frames`, used instead of the spinner type's.
+    frames: Option<(Arc<[String]>, std::time::Duration)>,
 }
 
 impl Default for Spinner {
@@ -263,6 +283,10 @@ impl Default for Spinner {
             frame: 0,
             tag: 0,
             color: None,
+            fps: None,
+            direction: SpinnerDirection::default(),
+            backward: false,
+            frames: None,
         }
     }
 }
@@ -273,6 +297,32 @@ impl Spinner {
         self.id
     }
 
+    /// Return the tag the next [`TickMsg`] must carry to be accepted.
+    pub fn tag(&self) -> usize {
+        self.tag
+    }
+
+    /// Return the index of the current animation frame.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Return the glyph of the current animation frame, without styling.
+    pub fn current_glyph(&self) -> &str {
+        match &self.frames {
+            Some((frames, _)) => &frames[self.frame],
+            None => self.spinner_type.frame(self.frame),
+        }
+    }
+
+    // Number of frames in the animation.
+    fn len(&self) -> usize {
+        match &self.frames {
+            Some((frames, _)) => frames.len(),
+            None => self.spinner_type.len(),
+        }
+    }
+
     /// Set the spinner color.
     pub fn set_color(self, color: Color) -> Self {
         Self {
@@ -286,10 +336,38 @@ impl Spinner {
         self.color
     }
 
-    /// Set the spinner type (frames + fps).
+    /// Show each frame for `fps` instead of the spinner type's own interval, e.g. to
+    /// slow the spinner down while the app is in the background.
+    pub fn set_fps(self, fps: std::time::Duration) -> Self {
+        Self {
+            fps: Some(fps),
+            ..self
+        }
+    }
+
+    /// Return the interval between frames used by [`Spinner::tick`].
+    pub fn fps(&self) -> std::time::Duration {
+        self.fps.unwrap_or_else(|| match &self.frames {
+            Some((_, fps)) => *fps,
+            None => self.spinner_type.fps(),
+        })
+    }
+
+    /// Set the order in which frames are played.
+    pub fn set_direction(self, direction: SpinnerDirection) -> Self {
+        Self {
+            direction,
+            backward: false,
+            ..self
+        }
+    }
+
+    /// Set the spinner type (frames + fps), replacing any [`Spinner::with_frames`] frames.
     pub fn set_spinner_type(self, spinner: SpinnerType) -> Self {
         Self {
             spinner_type: spinner,
+            frame: 0,
+            frames: None,
             ..self
         }
     }
@@ -308,14 +386,43 @@ impl Spinner {
         }
     }
 
+    /// Create a spinner cycling through arbitrary `frames`, showing each for `fps`.
+    ///
+    /// An empty list renders as a single empty frame.
+    pub fn with_frames(mut frames: Vec<String>, fps: std::time::Duration) -> Self {
+        if frames.is_empty() {
+            frames.push(String::new());
+        }
+        Self {
+            frames: Some((frames.into(), fps)),
+            ..Self::new(SpinnerType::line())
+        }
+    }
+
+    /// Stop advancing, keeping the current frame.
+    ///
+    /// The tick already in flight, and any other `TickMsg` issued before the pause, is
+    /// ignored when it arrives, so the animation stops there. Use [`Spinner::resume`] to
+    /// start it again.
+    pub fn pause(self) -> Self {
+        Self {
+            tag: self.tag.wrapping_add(1),
+            ..self
+        }
+    }
+
+    /// Return the tick command that continues a [`Spinner::pause`]d spinner from its
+    /// current frame.
+    pub fn resume(&self) -> Cmd {
+        self.tick(self.tag)
+    }
+
     /// Create a tick command that advances the spinner animation.
     ///
     /// `tag` is used to prevent out-of-order tick bursts.
     pub fn tick(&self, tag: usize) -> Cmd {
         let id = self.id;
-        tick(self.spinner_type.fps(), move || {
-            Box::new(TickMsg { id, tag })
-        })
+        tick(self.fps(), move || Box::new(TickMsg { id, tag }))
     }
 }
 
@@ -346,20 +453,42 @@ impl Model for Spinner {
                 return (self, None);
             }
 
-            let f = if self.frame == self.spinner_type.frames().len() - 1 {
-                0
-            } else {
-                self.frame + 1
+            let last = self.len() - 1;
+            let (f, backward) = match self.direction {
+                SpinnerDirection::Forward if self.frame == last => (0, false),
+                SpinnerDirection::Forward => (self.frame + 1, false),
+                SpinnerDirection::Reverse if self.frame == 0 => (last, false),
+                SpinnerDirection::Reverse => (self.frame - 1, false),
+                SpinnerDirection::PingPong => {
+                    // Turn around at either end; a single frame just stays put.
+                    let backward = if self.frame == last {
+                        true
+                    } else if self.frame == 0 {
+                        false
+                    } else {
+                        self.backward
+                    };
+                    let f = if last == 0 {
+                        0
+                    } else if backward {
+                        self.frame - 1
+                    } else {
+                        self.frame + 1
+                    };
+                    (f, backward)
+                }
             };
 
-            let tag = self.tag + 1;
+            let tag = self.tag.wrapping_add(1);
+            let cmd = self.tick(tag);
             return (
                 Self {
                     frame: f,
                     tag,
+                    backward,
                     ..self
                 },
-                Some(self.tick(tag)),
+                Some(cmd),
             );
         };
         (self, None)
@@ -367,10 +496,10 @@ impl Model for Spinner {
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn view(&self) -> impl Display {
-        if self.frame >= self.spinner_type.len() {
+        if self.frame >= self.len() {
             unreachable!("frame out of range");
         }
-        let s = self.spinner_type.frames()[self.frame].to_string();
+        let s = self.current_glyph().to_string();
         if let Some(color) = self.color {
             style(s).with(color).to_string()
         } else {
@@ -378,3 +507,140 @@ impl Model for Spinner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_and_glyph_advance_together_and_wrap() {
+        let mut spinner = Spinner::new(SpinnerType::line());
+        assert_eq!((spinner.frame(), spinner.current_glyph()), (0, "|"));
+
+        let mut glyphs = vec![];
+        for tag in 0..4 {
+            let tick: Msg = Box::new(TickMsg {
+                id: spinner.id(),
+                tag,
+            });
+            spinner = spinner.update(&tick).0;
+            assert_eq!(spinner.current_glyph(), spinner.view().to_string());
+            glyphs.push((spinner.frame(), spinner.current_glyph().to_string()));
+        }
+        let expected = [(1, "/"), (2, "-"), (3, "\\"), (0, "|")];
+        assert_eq!(glyphs, expected.map(|(f, g)| (f, g.to_string())));
+    }
+
+    #[test]
+    fn tick_waits_for_the_fps_override() {
+        let spinner =
+            Spinner::new(SpinnerType::line()).set_fps(std::time::Duration::from_millis(300));
+        assert_eq!(spinner.fps(), std::time::Duration::from_millis(300));
+
+        let Cmd::Sync(SyncCmd(cmd)) = spinner.tick(0) else {
+            panic!("tick should be a sync command");
+        };
+        let start = std::time::Instant::now();
+        let msg = cmd();
+        assert!(start.elapsed() >= std::time::Duration::from_millis(300));
+        assert!(msg.downcast_ref::<TickMsg>().is_some());
+    }
+
+    fn frames(frames: &[&str]) -> Vec<String> {
+        frames.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn frames_played(direction: SpinnerDirection, ticks: usize) -> Vec<usize> {
+        let mut spinner = Spinner::with_frames(frames(&["a", "b", "c"]), std::time::Duration::ZERO)
+            .set_direction(direction);
+        let mut frames = vec![];
+        for tag in 0..ticks {
+            let tick: Msg = Box::new(TickMsg {
+                id: spinner.id(),
+                tag,
+            });
+            spinner = spinner.update(&tick).0;
+            frames.push(spinner.frame());
+        }
+        frames
+    }
+
+    #[test]
+    fn directions_play_frames_in_order() {
+        assert_eq!(
+            frames_played(SpinnerDirection::Forward, 4),
+            vec![1, 2, 0, 1]
+        );
+        assert_eq!(
+            frames_played(SpinnerDirection::Reverse, 4),
+            vec![2, 1, 0, 2]
+        );
+        assert_eq!(
+            frames_played(SpinnerDirection::PingPong, 6),
+            vec![1, 2, 1, 0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn paused_spinner_ignores_ticks_until_resumed() {
+        let spinner = Spinner::with_frames(frames(&["a", "b", "c"]), std::time::Duration::ZERO);
+        let tick = |spinner: &Spinner, tag| -> Msg {
+            Box::new(TickMsg {
+                id: spinner.id(),
+                tag,
+            })
+        };
+        let first = tick(&spinner, 0);
+        let (spinner, _) = spinner.update(&first);
+        let in_flight = tick(&spinner, spinner.tag());
+
+        let spinner = spinner.pause();
+        let (spinner, cmd) = spinner.update(&in_flight);
+        assert!(cmd.is_none());
+        assert_eq!(spinner.frame(), 1);
+
+        let Cmd::Sync(SyncCmd(resume)) = spinner.resume() else {
+            panic!("resume should be a sync command");
+        };
+        let (spinner, cmd) = spinner.update(&resume());
+        assert!(cmd.is_some());
+        assert_eq!(spinner.frame(), 2);
+    }
+
+    #[test]
+    fn custom_frames_of_any_length_animate() {
+        let mut spinner = Spinner::with_frames(
+            frames(&["a", "bb", "c"]),
+            std::time::Duration::from_millis(50),
+        );
+        assert_eq!(spinner.fps(), std::time::Duration::from_millis(50));
+
+        let mut glyphs = vec![];
+        for tag in 0..3 {
+            let tick: Msg = Box::new(TickMsg {
+                id: spinner.id(),
+                tag,
+            });
+            spinner = spinner.update(&tick).0;
+            glyphs.push(spinner.view().to_string());
+        }
+        assert_eq!(glyphs, vec!["bb", "c", "a"]);
+    }
+
+    #[test]
+    fn custom_frames_are_built_at_runtime() {
+        let glyphs: Vec<String> = (1..=12).map(|n| format!("{n}/12")).collect();
+        let spinner = Spinner::with_frames(glyphs, std::time::Duration::ZERO);
+        assert_eq!(spinner.len(), 12);
+        assert_eq!(spinner.current_glyph(), "1/12");
+
+        let spinner = Spinner::with_frames(vec![], std::time::Duration::ZERO);
+        assert_eq!(spinner.current_glyph(), "");
+
+        // Spinner types stay `Copy`; setting one replaces the custom frames and interval.
+        let line = SpinnerType::line();
+        let spinner = spinner.set_spinner_type(line);
+        assert_eq!(spinner.current_glyph(), line.frame(0));
+        assert_eq!(spinner.fps(), line.fps());
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    fmt::Display,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use matcha::{tick, Cmd, Model, Msg};
+
+static ID: AtomicUsize = AtomicUsize::new(1);
+
+fn next_id() -> usize {
+    ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// An elapsed-time counter that counts up from zero, e.g. `Recording 00:00:37`.
+///
+/// The stopwatch advances by its interval on every [`ElapsedTickMsg`] addressed to it.
+/// Ticks carry the stopwatch id and a tag, so ticks for another stopwatch or ticks
+/// scheduled before a [`Elapsed::stop`] are ignored.
+pub struct Elapsed {
+    id: usize,
+    tag: usize,
+    running: bool,
+    elapsed: Duration,
+    interval: Duration,
+}
+
+impl Default for Elapsed {
+    fn default() -> Self {
+        Self {
+            id: next_id(),
+            tag: 0,
+            running: false,
+            elapsed: Duration::ZERO,
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Elapsed {
+    /// Create a stopped stopwatch at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the stopwatch's unique id.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Return the time counted so far.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Return whether the stopwatch is counting.
+    pub fn running(&self) -> bool {
+        self.running
+    }
+
+    /// Start (or resume) counting and return the first tick.
+    pub fn start(self) -> (Self, Option<Cmd>) {
+        if self.running {
+            return (self, None);
+        }
+        let next = Self {
+            running: true,
+            tag: self.tag + 1,
+            ..self
+        };
+        let cmd = next.tick();
+        (next, Some(cmd))
+    }
+
+    /// Stop counting. Ticks that are already scheduled are ignored.
+    pub fn stop(self) -> Self {
+        Self {
+            running: false,
+            tag: self.tag + 1,
+            ..self
+        }
+    }
+
+    /// Set the counted time back to zero without changing whether it runs.
+    pub fn reset(self) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            ..self
+        }
+    }
+
+    fn tick(&self) -> Cmd {
+        let (id, tag) = (self.id, self.tag);
+        tick(self.interval, move || Box::new(ElapsedTickMsg { id, tag }))
+    }
+}
+
+/// ElapsedTickMsg advances the [`Elapsed`] stopwatch with the matching id.
+pub struct ElapsedTickMsg {
+    /// Stopwatch id.
+    pub id: usize,
+    /// Tag used to reject ticks scheduled before the stopwatch was stopped.
+    pub tag: usize,
+}
+
+impl Model for Elapsed {
+    fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+        let Some(msg) = msg.downcast_ref::<ElapsedTickMsg>() else {
+            return (self, None);
+        };
+        if !self.running || msg.id != self.id || msg.tag != self.tag {
+            return (self, None);
+        }
+        let next = Self {
+            elapsed: self.elapsed + self.interval,
+            ..self
+        };
+        let cmd = next.tick();
+        (next, Some(cmd))
+    }
+
+    fn view(&self) -> impl Display {
+        let secs = self.elapsed.as_secs();
+        format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick_msg(stopwatch: &Elapsed, tag: usize) -> Msg {
+        Box::new(ElapsedTickMsg {
+            id: stopwatch.id(),
+            tag,
+        })
+    }
+
+    #[test]
+    fn ticks_advance_elapsed_while_running() {
+        let (mut stopwatch, cmd) = Elapsed::new().start();
+        assert!(cmd.is_some());
+        for _ in 0..61 {
+            let tick = tick_msg(&stopwatch, stopwatch.tag);
+            stopwatch = stopwatch.update(&tick).0;
+        }
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(61));
+        assert_eq!(stopwatch.view().to_string(), "00:01:01");
+    }
+
+    #[test]
+    fn stop_ignores_scheduled_ticks_and_reset_clears() {
+        let (stopwatch, _) = Elapsed::new().start();
+        let pending = tick_msg(&stopwatch, stopwatch.tag);
+        let stopwatch = stopwatch.update(&pending).0;
+        let scheduled = tick_msg(&stopwatch, stopwatch.tag);
+
+        let stopwatch = stopwatch.stop();
+        let (stopwatch, cmd) = stopwatch.update(&scheduled);
+        assert!(cmd.is_none());
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(1));
+
+        // A restarted stopwatch still rejects the tick scheduled before the stop.
+        let (stopwatch, _) = stopwatch.reset().start();
+        let (stopwatch, cmd) = stopwatch.update(&scheduled);
+        assert!(cmd.is_none());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+    }
+}
@@ -2,19 +2,37 @@ use std::fmt::Display;
 
 use unicode_width::UnicodeWidthStr;
 
+use matcha::crossterm::style::StyledContent;
 use matcha::{
-    batch, clamp_by, fill_by_space, remove_escape_sequences, style, Cmd, Color, InitInput, KeyCode,
-    KeyEvent, Model, Msg, Stylize,
+    clamp_by, fill_by_space, key, pad_center, remove_escape_sequences, style, Cmd, Color,
+    InitInput, Key, KeyCode, KeyEvent, Model, Msg, Stylize,
 };
 
 use matcha::DynModel;
 
+/// Title colors of a single [`Tab`]. `None` keeps the [`Tabs`] default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TabColors {
+    /// Title foreground while the tab is active.
+    pub active_fg: Option<Color>,
+    /// Title background while the tab is active.
+    pub active_bg: Option<Color>,
+    /// Title foreground while the tab is inactive.
+    pub inactive_fg: Option<Color>,
+    /// Title background while the tab is inactive.
+    pub inactive_bg: Option<Color>,
+}
+
 /// A single tab: a title plus a child model that renders the content.
 pub struct Tab {
     /// Tab title shown in the tab strip.
     pub title: String,
     /// Child model rendered when this tab is active.
     pub child: Box<dyn DynModel>,
+    // Title colors overriding the `Tabs` defaults.
+    colors: TabColors,
+    // A disabled tab is shown dimmed and never becomes active.
+    disabled: bool,
 }
 
 impl Tab {
@@ -23,20 +41,82 @@ impl Tab {
         Self {
             title: title.into(),
             child,
+            colors: TabColors::default(),
+            disabled: false,
         }
     }
+
+    /// Set the title colors of this tab.
+    pub fn colors(self, colors: TabColors) -> Self {
+        Self { colors, ..self }
+    }
+
+    /// Enable/disable this tab. A disabled tab is shown dimmed and skipped when
+    /// choosing the active tab.
+    pub fn disabled(self, disabled: bool) -> Self {
+        Self { disabled, ..self }
+    }
+
+    /// Return whether this tab is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+#[derive(Debug)]
+/// Message emitted when the active tab is closed with the close key, see
+/// [`Tabs::closeable`].
+pub struct TabClosedMsg {
+    /// Index the tab had before it was removed.
+    pub index: usize,
+    /// Title of the removed tab.
+    pub title: String,
+}
+
+/// How the tab strip is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabStyle {
+    /// 3-line rounded tabs above a top-less bordered window.
+    #[default]
+    Boxed,
+    /// A single line of titles with the active one highlighted and underlined,
+    /// followed by the content without a window.
+    Underline,
+}
+
+/// Where the tab titles are placed relative to the content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabsOrientation {
+    /// A tab strip above the content.
+    #[default]
+    Horizontal,
+    /// Titles stacked in a sidebar left of the content, like a settings page.
+    ///
+    /// Up/Down and k/j also switch tabs in this orientation.
+    Vertical,
 }
 
 /// A Bubble Tea "tabs" port: renders a tab strip and a window-like container below it.
 ///
 /// - Keybinds: Left/Right, h/l, p/n, Tab/Shift+Tab
 /// - Visual: 3-line rounded tabs with bottom join tweaks + top-less bordered window
+///   (or a single underlined strip, see [`TabStyle`])
 pub struct Tabs {
     width: u16,
     tabs: Vec<Tab>,
     active: usize,
     highlight: Color,
     content_padding_y: u16,
+    tab_style: TabStyle,
+    orientation: TabsOrientation,
+    // First tab shown in a boxed strip that is too wide for `width`.
+    tab_offset: usize,
+    // Input from `init`, with the size kept current, for tabs added later.
+    init_input: Option<InitInput>,
+    closeable: bool,
+    close_key: Key,
+    // Shown instead of the tabs once all of them are closed.
+    placeholder: String,
 }
 
 impl Tabs {
@@ -53,6 +133,13 @@ impl Tabs {
                 b: 0xF4,
             },
             content_padding_y: 2,
+            tab_style: TabStyle::default(),
+            orientation: TabsOrientation::default(),
+            tab_offset: 0,
+            init_input: None,
+            closeable: false,
+            close_key: Key(key!(ctrl - w)),
+            placeholder: String::new(),
         }
     }
 
@@ -77,36 +164,226 @@ impl Tabs {
         }
     }
 
+    /// Set how the tab strip is drawn.
+    pub fn tab_style(self, tab_style: TabStyle) -> Self {
+        Self { tab_style, ..self }
+    }
+
+    /// Set where the tab titles are placed. [`TabStyle`] only applies to
+    /// [`TabsOrientation::Horizontal`].
+    pub fn orientation(self, orientation: TabsOrientation) -> Self {
+        Self {
+            orientation,
+            ..self
+        }
+    }
+
+    /// Let the user close the active tab with the close key (`ctrl-w` by default).
+    ///
+    /// The active tab shows a `✕` marker, and closing it sends a [`TabClosedMsg`].
+    pub fn closeable(self, closeable: bool) -> Self {
+        Self { closeable, ..self }
+    }
+
+    /// Set the key that closes the active tab when [`Tabs::closeable`] is enabled.
+    pub fn close_key(self, close_key: Key) -> Self {
+        Self { close_key, ..self }
+    }
+
+    /// Set the text shown when there are no tabs left.
+    pub fn placeholder(self, placeholder: impl Into<String>) -> Self {
+        Self {
+            placeholder: placeholder.into(),
+            ..self
+        }
+    }
+
     /// Return the current active tab index.
     pub fn active_index(&self) -> usize {
         self.active
     }
 
+    /// Return the number of tabs.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Return true if there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Append a tab.
+    ///
+    /// Once the tabs have been initialized, the new tab's child is initialized with the
+    /// current size and its command is returned.
+    pub fn push_tab(&mut self, tab: Tab) -> Option<Cmd> {
+        let (tab, cmd) = self.init_tab(tab);
+        self.tabs.push(tab);
+        cmd
+    }
+
+    /// Remove and return the tab at `index`, or `None` if it is out of range.
+    ///
+    /// The active tab stays selected if it is not the one removed; otherwise the next
+    /// enabled tab (or the closest enabled one before it) becomes active.
+    pub fn remove_tab(&mut self, index: usize) -> Option<Tab> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+        let tab = self.tabs.remove(index);
+        if index < self.active {
+            self.active -= 1;
+        }
+        self.clamp_active();
+        self.scroll_to_active();
+        Some(tab)
+    }
+
+    /// Replace all tabs, initializing them like [`Tabs::push_tab`].
+    pub fn set_tabs(&mut self, tabs: Vec<Tab>) -> Option<Cmd> {
+        let mut cmds = vec![];
+        self.tabs = tabs
+            .into_iter()
+            .map(|tab| {
+                let (tab, cmd) = self.init_tab(tab);
+                cmds.extend(cmd);
+                tab
+            })
+            .collect();
+        self.clamp_active();
+        self.scroll_to_active();
+        matcha::cmds(cmds)
+    }
+
+    fn init_tab(&self, tab: Tab) -> (Tab, Option<Cmd>) {
+        let Some(input) = &self.init_input else {
+            return (tab, None);
+        };
+        let (child, cmd) = tab.child.init_box(input);
+        (Tab { child, ..tab }, cmd)
+    }
+
     fn clamp_active(&mut self) {
         if self.tabs.is_empty() {
             self.active = 0;
             return;
         }
         self.active = std::cmp::min(self.active, self.tabs.len() - 1);
+        if self.tabs[self.active].disabled {
+            self.step(true);
+        }
+        if self.tabs[self.active].disabled {
+            self.step(false);
+        }
+    }
+
+    /// The title as shown in the strip, with the close marker on the active tab.
+    fn label(&self, index: usize) -> String {
+        let title = &self.tabs[index].title;
+        if self.closeable && index == self.active {
+            format!("{} ✕", title)
+        } else {
+            title.clone()
+        }
+    }
+
+    /// The label of tab `index` with its colors resolved. `active_fg` is used for the
+    /// active tab unless the tab overrides it.
+    fn styled_label(&self, index: usize, active_fg: Option<Color>) -> StyledContent<String> {
+        let tab = &self.tabs[index];
+        let (fg, bg) = if index == self.active {
+            (tab.colors.active_fg.or(active_fg), tab.colors.active_bg)
+        } else {
+            (tab.colors.inactive_fg, tab.colors.inactive_bg)
+        };
+        let mut styled = style(self.label(index));
+        if let Some(fg) = fg {
+            styled = styled.with(fg);
+        }
+        if let Some(bg) = bg {
+            styled = styled.on(bg);
+        }
+        if tab.disabled {
+            styled = styled.dim();
+        }
+        styled
+    }
+
+    fn tab_width(&self, index: usize) -> u16 {
+        // Borders plus 1-cell padding on both sides, see `tab_block`.
+        Self::visible_width(&self.label(index)) + 4
+    }
+
+    /// Remove the active tab if the close key was pressed.
+    fn handle_close(&mut self, key: &KeyEvent) -> Option<Cmd> {
+        if !self.closeable || Key::from(key) != self.close_key {
+            return None;
+        }
+        let index = self.active;
+        let title = self.remove_tab(index)?.title;
+        Some(Cmd::sync(Box::new(move || {
+            Box::new(TabClosedMsg { index, title })
+        })))
+    }
+
+    /// The range of tabs shown in the boxed strip, starting at `tab_offset` and taking
+    /// as many as fit into `width` next to the overflow indicators.
+    fn visible_tabs(&self) -> std::ops::Range<usize> {
+        let len = self.tabs.len();
+        let start = std::cmp::min(self.tab_offset, len.saturating_sub(1));
+        if self.width == 0 {
+            return start..len;
+        }
+        let mut used = if start > 0 { 1 } else { 0 };
+        let mut end = start;
+        for i in start..len {
+            let indicator = if i + 1 < len { 1 } else { 0 };
+            let w = self.tab_width(i);
+            if used + w + indicator > self.width {
+                break;
+            }
+            used += w;
+            end = i + 1;
+        }
+        // Always show at least one tab, even if it gets clamped.
+        start..std::cmp::max(end, std::cmp::min(start + 1, len))
+    }
+
+    /// Scroll the strip so the active tab is visible.
+    fn scroll_to_active(&mut self) {
+        if self.active < self.tab_offset {
+            self.tab_offset = self.active;
+        }
+        while self.tab_offset < self.active && self.visible_tabs().end <= self.active {
+            self.tab_offset += 1;
+        }
+    }
+
+    /// Move to the closest enabled tab after (or before) the active one, if any.
+    fn step(&mut self, forward: bool) {
+        let next = if forward {
+            (self.active + 1..self.tabs.len()).find(|&i| !self.tabs[i].disabled)
+        } else {
+            (0..self.active).rev().find(|&i| !self.tabs[i].disabled)
+        };
+        if let Some(next) = next {
+            self.active = next;
+        }
     }
 
     fn handle_key(&mut self, key: &KeyEvent) {
         if self.tabs.is_empty() {
             return;
         }
+        let vertical = self.orientation == TabsOrientation::Vertical;
         match key.code {
-            KeyCode::Right | KeyCode::Tab => {
-                self.active = std::cmp::min(self.active + 1, self.tabs.len() - 1);
-            }
-            KeyCode::Left | KeyCode::BackTab => {
-                self.active = self.active.saturating_sub(1);
-            }
-            KeyCode::Char('l') | KeyCode::Char('n') => {
-                self.active = std::cmp::min(self.active + 1, self.tabs.len() - 1);
-            }
-            KeyCode::Char('h') | KeyCode::Char('p') => {
-                self.active = self.active.saturating_sub(1);
-            }
+            KeyCode::Right | KeyCode::Tab => self.step(true),
+            KeyCode::Left | KeyCode::BackTab => self.step(false),
+            KeyCode::Char('l') | KeyCode::Char('n') => self.step(true),
+            KeyCode::Char('h') | KeyCode::Char('p') => self.step(false),
+            KeyCode::Down | KeyCode::Char('j') if vertical => self.step(true),
+            KeyCode::Up | KeyCode::Char('k') if vertical => self.step(false),
             _ => {}
         }
     }
@@ -120,19 +397,7 @@ impl Tabs {
     }
 
     fn center_line(&self, line: String, width: u16) -> String {
-        let line = clamp_by(&line, width);
-        let w = Self::visible_width(&line);
-        if w >= width {
-            return line;
-        }
-        let left = (width - w) / 2;
-        let right = width - w - left;
-        format!(
-            "{}{}{}",
-            " ".repeat(left as usize),
-            line,
-            " ".repeat(right as usize)
-        )
+        pad_center(clamp_by(&line, width), width)
     }
 
     fn tab_block(
@@ -194,12 +459,22 @@ impl Tabs {
         if self.tabs.is_empty() {
             return vec![];
         }
-        let mut blocks: Vec<[String; 3]> = Vec::with_capacity(self.tabs.len());
-        for (i, tab) in self.tabs.iter().enumerate() {
+        let visible = self.visible_tabs();
+        let mut blocks: Vec<[String; 3]> = Vec::with_capacity(visible.len() + 2);
+        // Hidden tabs are shown as an arrow in an extra column whose bottom corner
+        // joins the window border below.
+        if visible.start > 0 {
+            blocks.push([" ".to_string(), "‹".to_string(), self.paint("╭")]);
+        }
+        for i in visible.clone() {
             let is_first = i == 0;
             let is_last = i == self.tabs.len() - 1;
             let is_active = i == self.active;
-            blocks.push(self.tab_block(&tab.title, is_active, is_first, is_last));
+            let title = self.styled_label(i, None).to_string();
+            blocks.push(self.tab_block(&title, is_active, is_first, is_last));
+        }
+        if visible.end < self.tabs.len() {
+            blocks.push([" ".to_string(), "›".to_string(), self.paint("╮")]);
         }
 
         let mut out = vec![String::new(), String::new(), String::new()];
@@ -211,6 +486,62 @@ impl Tabs {
         out
     }
 
+    fn underline_row(&self) -> String {
+        (0..self.tabs.len())
+            .map(|i| {
+                if i == self.active {
+                    self.styled_label(i, Some(self.highlight))
+                        .underlined()
+                        .to_string()
+                } else {
+                    self.styled_label(i, None).to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    fn vertical_view(&self, content: &str) -> String {
+        let title_w = (0..self.tabs.len())
+            .map(|i| Self::visible_width(&self.label(i)))
+            .max()
+            .unwrap_or(0);
+        // Marker, space, title, space.
+        let sidebar_w = title_w + 3;
+        // Separator and one space of padding before the content.
+        let content_w = self.width.saturating_sub(sidebar_w + 2);
+
+        let mut content_lines: Vec<&str> = vec![""; self.content_padding_y as usize];
+        content_lines.extend(content.split('\n'));
+        let height = std::cmp::max(self.tabs.len(), content_lines.len());
+
+        (0..height)
+            .map(|i| {
+                let entry = if i >= self.tabs.len() {
+                    " ".repeat(sidebar_w as usize)
+                } else if i == self.active {
+                    let title = self.styled_label(i, Some(self.highlight)).bold();
+                    format!(
+                        "{} {} ",
+                        self.paint("▌"),
+                        fill_by_space(title.to_string(), title_w)
+                    )
+                } else {
+                    let title = self.styled_label(i, None);
+                    format!("  {} ", fill_by_space(title.to_string(), title_w))
+                };
+                let line = content_lines.get(i).copied().unwrap_or("");
+                format!(
+                    "{}{} {}",
+                    entry,
+                    self.paint("│"),
+                    fill_by_space(clamp_by(line, content_w), content_w)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn window_view(&self, content: &str, width: u16) -> Vec<String> {
         // width is total window width including borders. We remove top border like lipgloss.UnsetBorderTop().
         let total_w = width.max(2);
@@ -252,64 +583,65 @@ impl Model for Tabs {
             if let Some(cmd) = cmd {
                 cmds.push(cmd);
             }
-            tabs.push(Tab {
-                title: tab.title,
-                child,
-            });
+            tabs.push(Tab { child, ..tab });
         }
 
-        let cmd = if cmds.is_empty() {
-            None
-        } else {
-            Some(batch(cmds))
-        };
+        let cmd = matcha::cmds(cmds);
 
         let mut next = Self {
             width: input.size.0,
             tabs,
+            init_input: Some(input.clone()),
             ..self
         };
         next.clamp_active();
+        next.scroll_to_active();
         (next, cmd)
     }
 
     fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
-        let mut width = self.width;
+        let mut next = self;
         if let Some(r) = msg.downcast_ref::<matcha::ResizeEvent>() {
-            width = r.0;
+            next.width = r.0;
+            if let Some(input) = &mut next.init_input {
+                input.size = (r.0, r.1);
+            }
         }
 
-        let mut next = Self { width, ..self };
+        let mut cmds = vec![];
         if let Some(key) = msg.downcast_ref::<KeyEvent>() {
+            cmds.extend(next.handle_close(key));
             next.handle_key(key);
             next.clamp_active();
         }
+        next.scroll_to_active();
 
-        let mut cmds = vec![];
         let mut tabs: Vec<Tab> = Vec::with_capacity(next.tabs.len());
         for tab in next.tabs.into_iter() {
             let (child, cmd) = tab.child.update_box(msg);
             if let Some(cmd) = cmd {
                 cmds.push(cmd);
             }
-            tabs.push(Tab {
-                title: tab.title,
-                child,
-            });
+            tabs.push(Tab { child, ..tab });
         }
         next.tabs = tabs;
 
-        let cmd = if cmds.is_empty() {
-            None
-        } else {
-            Some(batch(cmds))
-        };
+        let cmd = matcha::cmds(cmds);
         (next, cmd)
     }
 
     fn view(&self) -> impl Display {
         if self.tabs.is_empty() {
-            return String::new();
+            return self.placeholder.clone();
+        }
+
+        let active = std::cmp::min(self.active, self.tabs.len() - 1);
+        let content = self.tabs[active].child.view_string();
+        if self.orientation == TabsOrientation::Vertical {
+            return self.vertical_view(&content);
+        }
+        if self.tab_style == TabStyle::Underline {
+            return format!("{}\n{}", self.underline_row(), content);
         }
 
         let mut rows = self.tabs_row();
@@ -320,11 +652,220 @@ impl Model for Tabs {
             .unwrap_or(0);
 
         // Render active tab content inside a top-less bordered window.
-        let active = std::cmp::min(self.active, self.tabs.len() - 1);
-        let content = self.tabs[active].child.view_string();
         let window = self.window_view(&content, row_width);
 
         rows.extend(window);
         rows.join("\n")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use matcha::boxed;
+
+    struct Text(&'static str);
+
+    impl Model for Text {
+        fn view(&self) -> impl Display {
+            self.0
+        }
+    }
+
+    fn tabs() -> Tabs {
+        Tabs::new(vec![
+            Tab::new("One", boxed(Text("first"))),
+            Tab::new("Two", boxed(Text("second"))),
+        ])
+        .active(1)
+    }
+
+    #[test]
+    fn underline_style_renders_single_line_strip_and_content() {
+        let view = tabs().tab_style(TabStyle::Underline).view().to_string();
+        let lines: Vec<String> = view.lines().map(remove_escape_sequences).collect();
+
+        assert_eq!(lines, vec!["One  Two", "second"]);
+        assert!(
+            view.contains("\x1b[4m"),
+            "active title should be underlined"
+        );
+    }
+
+    #[test]
+    fn vertical_orientation_stacks_titles_beside_content() {
+        let tabs = tabs()
+            .orientation(TabsOrientation::Vertical)
+            .content_padding_y(0);
+        let (tabs, _) = tabs.init(&InitInput::new((14, 5)));
+        let lines: Vec<String> = tabs
+            .view()
+            .to_string()
+            .lines()
+            .map(remove_escape_sequences)
+            .collect();
+        assert_eq!(lines, vec!["  One │ second", "▌ Two │       "]);
+
+        let up: Msg = Box::new(KeyEvent::new(KeyCode::Up, matcha::KeyModifiers::NONE));
+        let (tabs, _) = tabs.update(&up);
+        assert_eq!(tabs.active_index(), 0);
+    }
+
+    #[test]
+    fn overflowing_strip_scrolls_to_the_active_tab() {
+        let tabs = Tabs::new(vec![
+            Tab::new("One", boxed(Text("1"))),
+            Tab::new("Two", boxed(Text("2"))),
+            Tab::new("Six", boxed(Text("6"))),
+        ])
+        .content_padding_y(0);
+        let (tabs, _) = tabs.init(&InitInput::new((16, 5)));
+        let strip = |tabs: &Tabs| -> Vec<String> {
+            let view = tabs.view().to_string();
+            view.lines().map(remove_escape_sequences).collect()
+        };
+        let lines = strip(&tabs);
+        assert_eq!(lines[1], "│ One ││ Two │›");
+        assert_eq!(lines[2], "│     └┴─────┴╮");
+        assert_eq!(lines[4], "└─────────────┘");
+
+        let right: Msg = Box::new(KeyEvent::new(KeyCode::Right, matcha::KeyModifiers::NONE));
+        let (tabs, _) = tabs.update(&right);
+        let (tabs, _) = tabs.update(&right);
+        let lines = strip(&tabs);
+        assert_eq!(lines[1], "‹│ Two ││ Six │");
+        assert_eq!(lines[2], "╭┴─────┴┘     │");
+        assert_eq!(lines[4], "└─────────────┘");
+    }
+
+    /// Renders the width it was initialized with.
+    struct InitWidth(u16);
+
+    impl Model for InitWidth {
+        fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+            (Self(input.size.0), None)
+        }
+
+        fn view(&self) -> impl Display {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn tabs_can_be_added_and_removed_at_runtime() {
+        let (mut tabs, _) = tabs().init(&InitInput::new((40, 10)));
+        let (next, _) = tabs.update(&(Box::new(matcha::ResizeEvent(30, 10)) as Msg));
+        tabs = next;
+
+        tabs.push_tab(Tab::new("New", boxed(InitWidth(0))));
+        tabs = tabs.active(2);
+        let view = tabs.view().to_string();
+        assert!(remove_escape_sequences(&view).contains("30"));
+
+        // Removing an earlier tab keeps the active one selected.
+        assert_eq!(tabs.remove_tab(0).map(|t| t.title).as_deref(), Some("One"));
+        assert_eq!(tabs.active_index(), 1);
+
+        // Removing the active last tab selects the new last one.
+        tabs.remove_tab(1);
+        assert_eq!((tabs.len(), tabs.active_index()), (1, 0));
+        assert!(tabs.remove_tab(5).is_none());
+
+        tabs.set_tabs(vec![]);
+        assert!(tabs.is_empty());
+        assert_eq!(tabs.view().to_string(), "");
+    }
+
+    #[test]
+    fn close_key_removes_active_tab_and_reports_it() {
+        let tabs = tabs().closeable(true).placeholder("No tabs");
+        let lines: Vec<String> = tabs
+            .view()
+            .to_string()
+            .lines()
+            .map(remove_escape_sequences)
+            .collect();
+        assert_eq!(lines[1], "│ One ││ Two ✕ │");
+        assert_eq!(lines[0].chars().count(), lines[1].chars().count());
+
+        let ctrl_w: Msg = Box::new(KeyEvent::new(
+            KeyCode::Char('w'),
+            matcha::KeyModifiers::CONTROL,
+        ));
+        let (tabs, cmd) = tabs.update(&ctrl_w);
+        let Some(Cmd::Sync(matcha::SyncCmd(cmd))) = cmd else {
+            panic!("closing a tab should send a message");
+        };
+        let msg = cmd();
+        let closed = msg.downcast_ref::<TabClosedMsg>().unwrap();
+        assert_eq!((closed.index, closed.title.as_str()), (1, "Two"));
+        assert_eq!((tabs.len(), tabs.active_index()), (1, 0));
+
+        let (tabs, _) = tabs.update(&ctrl_w);
+        assert_eq!(tabs.view().to_string(), "No tabs");
+    }
+
+    #[test]
+    fn navigation_skips_disabled_tabs_and_styles_titles() {
+        let red = TabColors {
+            inactive_fg: Some(Color::Red),
+            ..TabColors::default()
+        };
+        let tabs = Tabs::new(vec![
+            Tab::new("One", boxed(Text("1"))),
+            Tab::new("Two", boxed(Text("2"))).disabled(true),
+            Tab::new("Six", boxed(Text("6"))).colors(red),
+        ]);
+        let right: Msg = Box::new(KeyEvent::new(KeyCode::Right, matcha::KeyModifiers::NONE));
+        let left: Msg = Box::new(KeyEvent::new(KeyCode::Left, matcha::KeyModifiers::NONE));
+
+        let view = tabs.view().to_string();
+        assert!(view.contains(&style("Two".to_string()).dim().to_string()));
+        assert!(view.contains(&style("Six".to_string()).with(Color::Red).to_string()));
+
+        let (tabs, _) = tabs.update(&right);
+        assert_eq!(tabs.active_index(), 2);
+        let (tabs, _) = tabs.update(&right);
+        assert_eq!(tabs.active_index(), 2);
+        let (tabs, _) = tabs.update(&left);
+        assert_eq!(tabs.active_index(), 0);
+    }
+
+    #[test]
+    fn disabled_tabs_are_never_chosen_as_active() {
+        let tabs = Tabs::new(vec![
+            Tab::new("One", boxed(Text("1"))).disabled(true),
+            Tab::new("Two", boxed(Text("2"))),
+            Tab::new("Six", boxed(Text("6"))).disabled(true),
+            Tab::new("Ten", boxed(Text("10"))),
+        ]);
+        let (mut tabs, _) = tabs.init(&InitInput::default());
+        assert_eq!(tabs.active_index(), 1);
+
+        // The next tab is disabled, so the one after it takes over.
+        tabs.remove_tab(1);
+        assert_eq!(tabs.active_index(), 2);
+        assert_eq!(tabs.tabs[2].title, "Ten");
+
+        // Nothing enabled follows the removed tab, so look backwards.
+        let mut tabs = Tabs::new(vec![
+            Tab::new("Two", boxed(Text("2"))),
+            Tab::new("Six", boxed(Text("6"))).disabled(true),
+            Tab::new("Ten", boxed(Text("10"))),
+            Tab::new("Off", boxed(Text("0"))).disabled(true),
+        ])
+        .active(2);
+        tabs.remove_tab(2);
+        assert_eq!(tabs.active_index(), 0);
+    }
+
+    #[test]
+    fn boxed_style_renders_three_line_strip() {
+        let view = tabs().content_padding_y(0).view().to_string();
+        let lines: Vec<String> = view.lines().map(remove_escape_sequences).collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with('╭'));
+        assert!(lines[3].contains("second"));
+    }
+}
@@ -1,7 +1,7 @@
 use super::Position;
 use super::Row;
 
-#[derive(Default)]
+#[derive(Default, Clone, PartialEq)]
 /// Text buffer used by [`super::Textarea`].
 ///
 /// The document is represented as a vector of rows.
@@ -40,22 +40,21 @@ impl Document {
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Insert a character at a cursor position.
+    ///
+    /// Inserting on the line just past the last row starts a new row; an `x` past the
+    /// end of a row appends to it.
     pub fn insert(self, at: &Position, c: char) -> Self {
         if at.y > self.rows.len() {
             return self;
         }
-        if at.y == self.rows.len() {
-            // let mut row = Row::default();
-            // row.insert(0, c);
-            // self.rows.push(row);
-            self
-        } else {
-            let mut rows = self.rows;
-            if let Some(row) = rows.get_mut(at.y) {
-                row.insert(at.x, c);
-            }
-            Self { rows }
+        let mut rows = self.rows;
+        if at.y == rows.len() {
+            rows.push(Row::default());
+        }
+        if let Some(row) = rows.get_mut(at.y) {
+            row.insert(at.x, c);
         }
+        Self { rows }
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -97,6 +96,29 @@ impl Document {
         }
         Self { rows }
     }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Remove the rest of the row from a cursor position, returning the removed text.
+    pub fn kill_to_end(self, at: &Position) -> (Self, String) {
+        let mut rows = self.rows;
+        let Some(row) = rows.get_mut(at.y) else {
+            return (Self { rows }, String::new());
+        };
+        let tail = row.split(at.x);
+        (Self { rows }, tail.as_str().to_string())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Remove the row up to a cursor position, returning the removed text.
+    pub fn kill_to_start(self, at: &Position) -> (Self, String) {
+        let mut rows = self.rows;
+        let Some(row) = rows.get_mut(at.y) else {
+            return (Self { rows }, String::new());
+        };
+        let tail = row.split(at.x);
+        let head = std::mem::replace(row, tail);
+        (Self { rows }, head.as_str().to_string())
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +181,31 @@ mod tests {
             prop_assert_eq!(doc_to_strings(&doc), expected);
         }
 
+        #[test]
+        fn insert_on_virtual_last_row_appends_a_row(
+            rows in proptest::collection::vec(any::<String>(), 0..6),
+            x in any::<usize>(),
+            c in any::<char>(),
+        ) {
+            let len = rows.len();
+            let doc = doc_from_strings(&rows).insert(&Position::new(x, len), c);
+
+            let mut expected = rows.clone();
+            expected.push(c.to_string());
+            prop_assert_eq!(doc_to_strings(&doc), expected);
+        }
+
+        #[test]
+        fn insert_past_virtual_last_row_is_ignored(
+            rows in proptest::collection::vec(any::<String>(), 0..6),
+            y in 1usize..4,
+            c in any::<char>(),
+        ) {
+            let len = rows.len();
+            let doc = doc_from_strings(&rows).insert(&Position::new(0, len + y), c);
+            prop_assert_eq!(doc_to_strings(&doc), rows);
+        }
+
         #[test]
         fn insert_newline_keeps_or_increments_row_count(
             rows in proptest::collection::vec(any::<String>(), 0..6),
@@ -195,6 +242,26 @@ mod tests {
             prop_assert_eq!(actual[0].as_str(), format!("{left}{right}"));
         }
 
+        #[test]
+        fn kills_split_the_row_at_the_cursor(
+            rows in proptest::collection::vec(any::<String>(), 1..6),
+            y in any::<usize>(),
+            x in any::<usize>(),
+        ) {
+            let y = y % rows.len();
+            let x = x % (grapheme_len(&rows[y]) + 1);
+            let head: String = rows[y].graphemes(true).take(x).collect();
+            let tail: String = rows[y].graphemes(true).skip(x).collect();
+
+            let (doc, killed) = doc_from_strings(&rows).kill_to_end(&Position::new(x, y));
+            prop_assert_eq!(&killed, &tail);
+            prop_assert_eq!(doc.row(y).unwrap().as_str(), head.as_str());
+
+            let (doc, killed) = doc_from_strings(&rows).kill_to_start(&Position::new(x, y));
+            prop_assert_eq!(&killed, &head);
+            prop_assert_eq!(doc.row(y).unwrap().as_str(), tail.as_str());
+        }
+
         #[test]
         fn insert_newline_then_delete_restores_original_row(
             rows in proptest::collection::vec(any::<String>(), 1..6),
@@ -7,12 +7,13 @@ mod document;
 mod position;
 mod row;
 
-use std::fmt::Display;
+use std::{collections::VecDeque, fmt::Display, ops::Range};
 
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use document::Document;
-use matcha::{key, style, Cmd, Color, InitInput, KeyCode, KeyEvent, Model, Stylize};
+use matcha::{expand_tabs, key, style, Cmd, Color, InitInput, KeyCode, KeyEvent, Model, Stylize};
 use position::Position;
 use row::Row;
 
@@ -40,11 +41,32 @@ pub enum TextareaKeys {
     DeleteBack,
     /// Delete the character under the cursor.
     DeleteForward,
+    /// Move cursor to the start of the line.
+    LineStart,
+    /// Move cursor to the end of the line.
+    LineEnd,
+    /// Move cursor to the start of the previous word.
+    WordLeft,
+    /// Move cursor to the end of the next word.
+    WordRight,
+    /// Revert the last edit.
+    Undo,
+    /// Reapply the last undone edit.
+    Redo,
+    /// Cut to the end of the line, or join the next line at its end.
+    KillToLineEnd,
+    /// Cut to the start of the line.
+    KillToLineStart,
+    /// Paste the last cut text at the cursor.
+    Yank,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Default keybindings for [`Textarea`].
-pub struct Keybindings(matcha::KeyBindings<TextareaKeys>);
+///
+/// Start from [`Keybindings::default`] and use the [`matcha::KeyBindings`] helpers to
+/// rebind individual keys, then pass the result to [`Textarea::with_keybindings`].
+pub struct Keybindings(pub matcha::KeyBindings<TextareaKeys>);
 
 impl Default for Keybindings {
     fn default() -> Self {
@@ -62,7 +84,20 @@ impl Default for Keybindings {
             (key!(backspace), TextareaKeys::DeleteBack),
             (key!(ctrl - h), TextareaKeys::DeleteBack),
             (key!(delete), TextareaKeys::DeleteForward),
+            (key!(home), TextareaKeys::LineStart),
+            (key!(end), TextareaKeys::LineEnd),
+            (key!(ctrl - a), TextareaKeys::LineStart),
+            (key!(ctrl - e), TextareaKeys::LineEnd),
+            (key!(ctrl - left), TextareaKeys::WordLeft),
+            (key!(alt - b), TextareaKeys::WordLeft),
+            (key!(ctrl - right), TextareaKeys::WordRight),
+            (key!(alt - f), TextareaKeys::WordRight),
             (key!(ctrl - d), TextareaKeys::DeleteForward),
+            (key!(ctrl - z), TextareaKeys::Undo),
+            (key!(ctrl - y), TextareaKeys::Redo),
+            (key!(ctrl - k), TextareaKeys::KillToLineEnd),
+            (key!(ctrl - u), TextareaKeys::KillToLineStart),
+            (key!(alt - y), TextareaKeys::Yank),
         ]
         .into_iter()
         .collect();
@@ -141,6 +176,55 @@ impl Textarea {
         Self(Borderize { child, ..self.0 })
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set the number of columns between tab stops.
+    pub fn tab_width(self, tab_width: u16) -> Self {
+        let child = self.0.child.tab_width(tab_width);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Treat `tab_width` leading spaces as one indent level when deleting backwards.
+    pub fn soft_tabs(self, enabled: bool) -> Self {
+        let child = self.0.child.soft_tabs(enabled);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Limit the number of characters (graphemes, excluding line breaks).
+    pub fn char_limit(self, limit: Option<usize>) -> Self {
+        let child = self.0.child.char_limit(limit);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Limit the number of lines.
+    pub fn max_lines(self, limit: Option<usize>) -> Self {
+        let child = self.0.child.max_lines(limit);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Wrap long rows at the text width instead of scrolling horizontally.
+    pub fn soft_wrap(self, enabled: bool) -> Self {
+        let child = self.0.child.soft_wrap(enabled);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set the placeholder text shown while the textarea is empty.
+    pub fn placeholder(self, text: impl Into<String>) -> Self {
+        let child = self.0.child.placeholder(text);
+        Self(Borderize { child, ..self.0 })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Replace the keybindings.
+    pub fn with_keybindings(self, key_bindings: Keybindings) -> Self {
+        let child = self.0.child.with_keybindings(key_bindings);
+        Self(Borderize { child, ..self.0 })
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Focus the textarea (enables editing) and starts cursor blinking.
     pub fn focus(self) -> (Self, Option<Cmd>) {
@@ -155,6 +239,29 @@ impl Textarea {
         Self(Borderize::new(child))
     }
 
+    /// The current text, with rows joined by `\n`.
+    pub fn value(&self) -> String {
+        self.0.child.value()
+    }
+
+    /// The cursor as a 1-based `(line, column)` pair, with columns counted in
+    /// graphemes.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        self.0.child.cursor_position()
+    }
+
+    /// Number of lines in the document.
+    pub fn line_count(&self) -> usize {
+        self.0.child.line_count()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Replace the text, keeping the cursor within the new content.
+    pub fn set_value(self, content: impl Into<String>) -> Self {
+        let child = self.0.child.set_value(content);
+        Self(Borderize { child, ..self.0 })
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Enable a simple left border.
     pub fn border(self) -> Self {
@@ -165,11 +272,25 @@ impl Textarea {
     }
 }
 
+#[derive(Debug)]
+/// Message emitted when typing is rejected by [`Textarea::char_limit`] or
+/// [`Textarea::max_lines`], e.g. to ring a bell or flash the border.
+pub struct LimitReachedMsg;
+
+/// Maximum number of edits kept for undo.
+const MAX_UNDO: usize = 100;
+
+/// Document state restored by undo and redo.
+struct Snapshot {
+    document: Document,
+    cursor_position: Position,
+}
+
 /// Internal textarea implementation.
 ///
 /// This type handles editing behavior and rendering; it is wrapped by [`Textarea`].
 pub struct Inner {
-    // placeholder: String,
+    placeholder: String,
     width: u16,
     height: u16,
     document: Document,
@@ -180,12 +301,26 @@ pub struct Inner {
     key_bindings: Keybindings,
     show_line_numbers: bool,
     highlight_comment_lines: bool,
+    tab_width: u16,
+    // When enabled, backspace in leading spaces deletes back to the previous tab stop.
+    soft_tabs: bool,
+    // When enabled, rows wrap at the text width and `offset.y` counts display lines.
+    soft_wrap: bool,
+    undo_stack: VecDeque<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    // Set after a character insert that started or joined an undo group, so the next
+    // one joins it too.
+    coalesce_insert: bool,
+    char_limit: Option<usize>,
+    max_lines: Option<usize>,
+    // Text removed by the last kill, inserted again by yank.
+    kill_buffer: String,
 }
 
 impl Default for Inner {
     fn default() -> Self {
         Self {
-            // placeholder: String::default(),
+            placeholder: String::default(),
             width: 0,
             height: 0,
             document: Document::default(),
@@ -196,6 +331,15 @@ impl Default for Inner {
             key_bindings: Keybindings::default(),
             show_line_numbers: true,
             highlight_comment_lines: false,
+            tab_width: 4,
+            soft_tabs: false,
+            soft_wrap: false,
+            undo_stack: VecDeque::new(),
+            redo_stack: vec![],
+            coalesce_insert: false,
+            char_limit: None,
+            max_lines: None,
+            kill_buffer: String::default(),
         }
     }
 }
@@ -264,6 +408,75 @@ impl Inner {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set the number of columns between tab stops.
+    pub fn tab_width(self, tab_width: u16) -> Self {
+        Self { tab_width, ..self }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Treat `tab_width` leading spaces as one indent level when deleting backwards.
+    pub fn soft_tabs(self, enabled: bool) -> Self {
+        Self {
+            soft_tabs: enabled,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Limit the number of characters (graphemes, excluding line breaks).
+    ///
+    /// Typing past the limit does nothing and sends a [`LimitReachedMsg`].
+    pub fn char_limit(self, limit: Option<usize>) -> Self {
+        Self {
+            char_limit: limit,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Limit the number of lines.
+    ///
+    /// Inserting a newline past the limit does nothing and sends a [`LimitReachedMsg`].
+    pub fn max_lines(self, limit: Option<usize>) -> Self {
+        Self {
+            max_lines: limit,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Wrap long rows at the text width instead of scrolling horizontally.
+    ///
+    /// Cursor movement, scrolling and the line number gutter then work on display
+    /// lines; only the first display line of a row shows its number.
+    pub fn soft_wrap(self, enabled: bool) -> Self {
+        Self {
+            soft_wrap: enabled,
+            offset: Position::new(0, 0),
+            ..self
+        }
+        .scroll()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set the placeholder text shown while the document is empty.
+    pub fn placeholder(self, text: impl Into<String>) -> Self {
+        Self {
+            placeholder: text.into(),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Replace the keybindings.
+    pub fn with_keybindings(self, key_bindings: Keybindings) -> Self {
+        Self {
+            key_bindings,
+            ..self
+        }
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Focus the textarea for editing.
     pub fn focus(self) -> (Self, Option<Cmd>) {
@@ -281,11 +494,7 @@ impl Inner {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Create an inner textarea initialized with the given content.
     pub fn with_content(content: impl Into<String>) -> Self {
-        let mut rows = Vec::new();
-        for value in content.into().lines() {
-            rows.push(Row::from(value));
-        }
-
+        let rows = Self::split_rows(&content.into());
         let cursor = Self::set_cursor_char(Position::new(0, 0), cursor::Cursor::new(), &rows);
 
         Self {
@@ -295,24 +504,83 @@ impl Inner {
         }
     }
 
+    /// Split `content` into rows at each `\n`, dropping the `\r` of a `\r\n`.
+    ///
+    /// Unlike [`str::lines`], a trailing newline keeps the empty row after it, so
+    /// [`Inner::value`] gives the same text back.
+    fn split_rows(content: &str) -> Vec<Row> {
+        content
+            .split('\n')
+            .map(|line| Row::from(line.strip_suffix('\r').unwrap_or(line)))
+            .collect()
+    }
+
+    /// The current text, with rows joined by `\n`.
+    pub fn value(&self) -> String {
+        self.document
+            .rows()
+            .iter()
+            .map(Row::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The cursor as a 1-based `(line, column)` pair, with columns counted in
+    /// graphemes.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let Position { x, y } = self.cursor_position;
+        (y.saturating_add(1), x.saturating_add(1))
+    }
+
+    /// Number of lines in the document. An empty document still has one line.
+    pub fn line_count(&self) -> usize {
+        self.document.len().max(1)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Replace the text, keeping the cursor within the new content.
+    ///
+    /// An empty `content` leaves a single empty row to edit.
+    pub fn set_value(self, content: impl Into<String>) -> Self {
+        let rows = Self::split_rows(&content.into());
+        let y = self.cursor_position.y.min(rows.len() - 1);
+        let x = self.cursor_position.x.min(rows[y].len());
+        let cursor_position = Position::new(x, y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, &rows);
+        Self {
+            document: Document::with_rows(rows),
+            cursor,
+            cursor_position,
+            ..self
+        }
+        .scroll()
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn set_cursor_char(cursor_position: Position, cursor: Cursor, rows: &[Row]) -> Cursor {
         let Position { x, y } = cursor_position;
+        // A position past the last row (e.g. in an empty document) shows a blank cursor.
         let c: String = rows
             .get(y)
-            .expect("rows should not be empty")
-            .as_str()
-            .graphemes(true)
-            .nth(x)
+            .and_then(|row| row.as_str().graphemes(true).nth(x))
+            .filter(|&c| c != "\t")
             .unwrap_or(" ")
             .into();
         cursor.set_char(c)
     }
 
+    /// Expand tabs in `s`, which starts at display `column` of its row.
+    ///
+    /// Tab stops are relative to the start of the row, not to `s`.
+    fn expand_tabs_at(&self, s: &str, column: usize) -> String {
+        let expanded = expand_tabs(&(" ".repeat(column) + s), self.tab_width);
+        expanded[column..].to_string()
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn render_row(&self, row: &Row, index: usize) -> String {
         let start = self.offset.x;
-        let line_number_width = if self.show_line_numbers { 4 } else { 0 };
+        let line_number_width = self.line_number_width();
         // sub numbering
         let end = self
             .offset
@@ -321,61 +589,164 @@ impl Inner {
             // line number
             .saturating_sub(line_number_width);
 
+        // Display column where the visible part of the row begins.
+        let column = expand_tabs(&row.render(0, start), self.tab_width).width();
+        self.render_range(row, start..end, column, self.cursor_position.y == index)
+    }
+
+    /// Render the graphemes of `row` in `range`, which starts at display `column`.
+    fn render_range(&self, row: &Row, range: Range<usize>, column: usize, cursor: bool) -> String {
+        let Range { start, end } = range;
         let s = row.render(start, end);
-        if self.cursor_position.y != index {
-            return self.maybe_style_comment_line(row, s);
+        if !cursor {
+            return self.maybe_style_comment_line(row, self.expand_tabs_at(&s, column));
         }
 
+        // `cursor_x` is a grapheme index into the unexpanded row, so split before expanding.
         let cursor_x = self.cursor_position.x.saturating_sub(start);
+        let (head, rest) = split_at(s, cursor_x);
+        let head = self.expand_tabs_at(&head, column);
+        let column = column + head.width();
 
-        if cursor_x == 0 {
-            let (_, tail) = split_at(s, 1);
-            return self.maybe_style_comment_line(row, format!("{}", self.cursor.view()) + &tail);
+        if rest.is_empty() {
+            let rendered = if self.focus || cursor_x == 0 {
+                head + &format!("{}", self.cursor.view())
+            } else {
+                head
+            };
+            return self.maybe_style_comment_line(row, rendered);
         }
 
-        // IMPORTANT:
-        // `cursor_x` is a grapheme index, while `s.len()` is byte length.
-        // Comparing them causes incorrect "cursor is inside line" detection for multibyte text.
-        // As a result, the last grapheme can be replaced by the cursor on Japanese text.
-        let grapheme_len = s.graphemes(true).count();
-        if cursor_x < grapheme_len {
-            let (head, tail) = split_at(s, cursor_x);
-            let tail = if tail.is_empty() {
-                tail
+        let (under, tail) = split_at(rest, 1);
+        // The cursor takes the first cell of an expanded tab.
+        let under = self.expand_tabs_at(&under, column);
+        let under_width = under.width();
+        let cursor = format!("{}", self.cursor.view())
+            + &" ".repeat(if under.trim().is_empty() {
+                under_width.saturating_sub(1)
             } else {
-                let (_, tail) = split_at(tail, 1);
-                tail
-            };
-            return self
-                .maybe_style_comment_line(row, head + &format!("{}", self.cursor.view()) + &tail);
+                0
+            });
+        let tail = self.expand_tabs_at(&tail, column + under_width);
+        self.maybe_style_comment_line(row, head + &cursor + &tail)
+    }
+
+    /// Whether the document has no text: no rows, or a single empty row.
+    fn is_empty(&self) -> bool {
+        match self.document.rows() {
+            [] => true,
+            [row] => row.len() == 0,
+            _ => false,
         }
+    }
 
-        let rendered = if self.focus {
-            s + &format!("{}", self.cursor.view())
-        } else {
-            s
-        };
-        self.maybe_style_comment_line(row, rendered)
+    /// The first row of an empty document: the cursor followed by the dimmed placeholder.
+    fn render_placeholder(&self) -> String {
+        let width = self.text_width();
+        let mut s = String::default();
+        let mut used = 0;
+        if self.focus {
+            s += &format!("{}", self.cursor.view());
+            used += 1;
+        }
+        let mut placeholder = String::default();
+        for g in self.placeholder.graphemes(true) {
+            used += g.width();
+            if used > width {
+                break;
+            }
+            placeholder += g;
+        }
+        s + &style(placeholder).dim().to_string()
+    }
+
+    /// Row `y` split into display lines at the text width.
+    ///
+    /// A cursor after the last grapheme takes a column of its own, so it gets an empty
+    /// display line when the last one is already full.
+    fn wrap_row(&self, y: usize, row: &Row) -> Vec<Range<usize>> {
+        let width = self.text_width();
+        let mut ranges = row.wrap_ranges(width, self.tab_width as usize);
+        let at_end = self.cursor_position == Position::new(row.len(), y) && row.len() > 0;
+        if let Some(last) = ranges.last().filter(|_| at_end) {
+            let s = self.expand_tabs_at(&row.render(last.start, last.end), 0);
+            if matcha::display_width(&s) as usize >= width {
+                ranges.push(row.len()..row.len());
+            }
+        }
+        ranges
+    }
+
+    /// Every row split into display lines at the text width, as `(row index, range)`.
+    fn display_lines(&self) -> Vec<(usize, Range<usize>)> {
+        self.document
+            .rows()
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| self.wrap_row(y, row).into_iter().map(move |r| (y, r)))
+            .collect()
+    }
+
+    /// Index into `lines` of the display line holding the cursor.
+    fn cursor_display_line(&self, lines: &[(usize, Range<usize>)]) -> usize {
+        let start = self.cursor_line_range().start;
+        lines
+            .iter()
+            .position(|(y, range)| *y == self.cursor_position.y && range.start == start)
+            .unwrap_or(0)
+    }
+
+    /// Render display line `n` of a soft-wrapped document, gutter included.
+    fn render_wrapped_line(&self, lines: &[(usize, Range<usize>)], n: usize) -> Option<String> {
+        let (y, range) = lines.get(n)?;
+        let row = self.document.row(*y)?;
+        let mut s = String::default();
+        if self.line_number_width() > 0 {
+            s += &if range.start == 0 {
+                self.line_number(*y)
+            } else {
+                " ".repeat(self.line_number_width())
+            };
+        }
+        let cursor = n == self.cursor_display_line(lines);
+        Some(s + &self.render_range(row, range.clone(), 0, cursor))
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn render_rows(&self) -> String {
         let height = self.height;
+        let show_placeholder = !self.placeholder.is_empty() && self.is_empty();
+        let lines = if self.soft_wrap {
+            self.display_lines()
+        } else {
+            vec![]
+        };
+        let gutter = |n: usize| {
+            if self.line_number_width() > 0 {
+                self.line_number(n)
+            } else {
+                String::default()
+            }
+        };
         let mut rows = vec![];
         for row in 0..height {
-            let mut s = String::default();
             let n = self.offset.y.saturating_add(row as usize);
-            if let Some(row) = self.document.row(n) {
-                if self.show_line_numbers {
-                    s += &format!("{:>3} ", n.saturating_add(1));
-                }
-                s += &self.render_row(row, n);
-            } else if self.show_line_numbers {
-                s += &format!("{:>1} ~", " ");
+            let line = if show_placeholder && n == 0 {
+                Some(gutter(0) + &self.render_placeholder())
+            } else if self.soft_wrap {
+                self.render_wrapped_line(&lines, n)
             } else {
-                s.push('~');
-            }
-            rows.push(s);
+                self.document
+                    .row(n)
+                    .map(|row| gutter(n) + &self.render_row(row, n))
+            };
+            rows.push(line.unwrap_or_else(|| {
+                if self.line_number_width() > 0 {
+                    format!("{:>1} ~", " ")
+                } else {
+                    "~".to_string()
+                }
+            }));
         }
         rows.join("\n")
     }
@@ -432,8 +803,58 @@ impl Inner {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn move_word_left(self) -> Self {
+        let Position { y, x } = self.cursor_position;
+        let start = self.document.row(y).and_then(|row| {
+            row.word_ranges()
+                .into_iter()
+                .rev()
+                .find(|word| word.start < x)
+                .map(|word| word.start)
+        });
+        match start {
+            Some(x) => self.move_to(Position::new(x, y)),
+            // At the first word: go to the row start, then wrap to the previous row.
+            None if x > 0 => self.move_to(Position::new(0, y)),
+            None => self.move_left(),
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn move_word_right(self) -> Self {
+        let Position { y, x } = self.cursor_position;
+        let Some(row) = self.document.row(y) else {
+            return self;
+        };
+        let len = row.len();
+        let end = row
+            .word_ranges()
+            .into_iter()
+            .find(|word| word.end > x)
+            .map(|word| word.end);
+        match end {
+            Some(x) => self.move_to(Position::new(x, y)),
+            // Past the last word: go to the row end, then wrap to the next row.
+            None if x < len => self.move_to(Position::new(len, y)),
+            None => self.move_right(),
+        }
+    }
+
+    fn move_to(self, cursor_position: Position) -> Self {
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, self.document.rows());
+        Self {
+            cursor_position,
+            cursor,
+            ..self
+        }
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn move_up(self) -> Self {
+        if self.soft_wrap {
+            return self.move_display_line(false);
+        }
         let Position { mut y, mut x } = self.cursor_position;
         y = y.saturating_sub(1);
         if let Some(row) = self.document.row(y) {
@@ -454,6 +875,9 @@ impl Inner {
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn move_down(self) -> Self {
+        if self.soft_wrap {
+            return self.move_display_line(true);
+        }
         let Position { mut y, mut x } = self.cursor_position;
         let height = self.document.len();
         if let Some(next) = self.document.row(y + 1) {
@@ -475,23 +899,165 @@ impl Inner {
         }
     }
 
+    /// Move to the previous or next display line, keeping the display column.
+    ///
+    /// The cursor lands on the grapheme covering the column, or the last position of
+    /// the line when it is shorter.
+    fn move_display_line(self, down: bool) -> Self {
+        let lines = self.display_lines();
+        let current = self.cursor_display_line(&lines);
+        let target = if down {
+            current.saturating_add(1)
+        } else {
+            match current.checked_sub(1) {
+                Some(target) => target,
+                None => return self,
+            }
+        };
+        let (Some((cy, current_range)), Some((y, range))) = (lines.get(current), lines.get(target))
+        else {
+            return self;
+        };
+        let (Some(current_row), Some(row)) = (self.document.row(*cy), self.document.row(*y)) else {
+            return self;
+        };
+        let column = current_row
+            .render(current_range.start, self.cursor_position.x)
+            .width();
+        let mut x = range.start;
+        let mut width = 0;
+        for g in row.render(range.start, range.end).graphemes(true) {
+            width += g.width();
+            if width > column {
+                break;
+            }
+            x += 1;
+        }
+        // Only the last display line of a row has a position after its last grapheme.
+        if range.end < row.len() {
+            x = x.min(range.end.saturating_sub(1));
+        }
+        let cursor_position = Position::new(x, *y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, self.document.rows());
+        Self {
+            cursor_position,
+            cursor,
+            ..self
+        }
+    }
+
+    /// Width of the line number gutter: the digits of the largest line number (at least
+    /// three) plus a separating space. The gutter is dropped when it would leave no room
+    /// for text.
+    fn line_number_width(&self) -> usize {
+        let width = self.document.len().max(1).to_string().len().max(3) + 1;
+        if self.show_line_numbers && (self.width as usize) > width {
+            width
+        } else {
+            0
+        }
+    }
+
+    /// Gutter text for the row at `index`, right-aligned to the gutter width.
+    fn line_number(&self, index: usize) -> String {
+        let digits = self.line_number_width().saturating_sub(1);
+        format!("{:>digits$} ", index.saturating_add(1))
+    }
+
+    /// Width available for text, excluding the line number gutter.
+    fn text_width(&self) -> usize {
+        (self.width as usize).saturating_sub(self.line_number_width())
+    }
+
+    /// Grapheme range of the line under the cursor: the visual line when soft wrap is
+    /// enabled, the whole logical row otherwise.
+    fn cursor_line_range(&self) -> Range<usize> {
+        let Position { x, y } = self.cursor_position;
+        let Some(row) = self.document.row(y) else {
+            return 0..0;
+        };
+        if !self.soft_wrap {
+            return 0..row.len();
+        }
+        let ranges = self.wrap_row(y, row);
+        let last = ranges.len() - 1;
+        ranges
+            .into_iter()
+            .enumerate()
+            .find(|(i, range)| range.contains(&x) || *i == last)
+            .map(|(_, range)| range)
+            .expect("wrap_ranges is never empty")
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn move_to_line_start(self) -> Self {
+        let cursor_position = Position::new(self.cursor_line_range().start, self.cursor_position.y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, self.document.rows());
+        Self {
+            cursor_position,
+            cursor,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn move_to_line_end(self) -> Self {
+        let range = self.cursor_line_range();
+        let row_len = self
+            .document
+            .row(self.cursor_position.y)
+            .map_or(0, |row| row.len());
+        // A wrapped visual line ends on its last grapheme; the position after it already
+        // belongs to the next visual line.
+        let x = if range.end < row_len {
+            range.end.saturating_sub(1)
+        } else {
+            range.end
+        };
+        let cursor_position = Position::new(x, self.cursor_position.y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, self.document.rows());
+        Self {
+            cursor_position,
+            cursor,
+            ..self
+        }
+    }
+
+    fn can_insert(&self) -> bool {
+        let count: usize = self.document.rows().iter().map(Row::len).sum();
+        self.char_limit.map_or(true, |limit| count < limit)
+    }
+
+    fn can_insert_newline(&self) -> bool {
+        self.max_lines
+            .map_or(true, |limit| self.document.len() < limit)
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn insert(self, c: char) -> Self {
+        if !self.can_insert() {
+            return self;
+        }
+        let Position { x, y } = self.cursor_position;
+        let row_len = |document: &Document| document.row(y).map_or(0, |row| row.len());
+        let before = row_len(&self.document);
         let document = self.document.insert(&self.cursor_position, c);
-        let cursor = Self::set_cursor_char(self.cursor_position, self.cursor, document.rows());
+        // A combining mark merges into the previous grapheme and doesn't move the cursor.
+        let cursor_position = Position::new(x + row_len(&document).saturating_sub(before), y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, document.rows());
         Self {
             document,
             cursor,
-            cursor_position: Position::new(
-                self.cursor_position.x.saturating_add(1),
-                self.cursor_position.y,
-            ),
+            cursor_position,
             ..self
         }
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn insert_newline(self) -> Self {
+        if !self.can_insert_newline() {
+            return self;
+        }
         let document = self.document.insert_newline(&self.cursor_position);
         Self {
             document,
@@ -500,9 +1066,85 @@ impl Inner {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn kill_to_line_end(self) -> Self {
+        let Position { x, y } = self.cursor_position;
+        let Some(row) = self.document.row(y) else {
+            return self;
+        };
+        if x >= row.len() {
+            // At the end of a line this joins the next one, like `delete_forward`.
+            if self.document.row(y + 1).is_none() {
+                return self;
+            }
+            return Self {
+                kill_buffer: "\n".to_string(),
+                ..self.delete_forward()
+            };
+        }
+        let (document, kill_buffer) = self.document.kill_to_end(&self.cursor_position);
+        let cursor = Self::set_cursor_char(self.cursor_position, self.cursor, document.rows());
+        Self {
+            document,
+            cursor,
+            kill_buffer,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn kill_to_line_start(self) -> Self {
+        if self.cursor_position.x == 0 {
+            return self;
+        }
+        let (document, kill_buffer) = self.document.kill_to_start(&self.cursor_position);
+        let cursor_position = Position::new(0, self.cursor_position.y);
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, document.rows());
+        Self {
+            document,
+            cursor,
+            cursor_position,
+            kill_buffer,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn yank(self) -> Self {
+        let buffer = self.kill_buffer.clone();
+        buffer.chars().fold(self, |inner, c| match c {
+            '\n' => inner.insert_newline(),
+            c => inner.insert(c),
+        })
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Delete the character before the cursor.
+    ///
+    /// With soft tabs enabled and the cursor in the leading spaces of a row, this deletes
+    /// back to the previous tab stop instead.
     pub fn delete_back(self) -> Self {
+        let count = self.soft_tab_len_before_cursor().unwrap_or(1);
+        (0..count).fold(self, |s, _| s.delete_back_char())
+    }
+
+    /// Number of spaces back to the previous tab stop, if the cursor is in leading spaces.
+    fn soft_tab_len_before_cursor(&self) -> Option<usize> {
+        let Position { x, y } = self.cursor_position;
+        let tab_width = self.tab_width as usize;
+        if !self.soft_tabs || tab_width == 0 || x == 0 {
+            return None;
+        }
+        let row = self.document.row(y)?;
+        let leading_spaces = row.as_str().chars().take_while(|c| *c == ' ').count();
+        if x > leading_spaces {
+            return None;
+        }
+        Some(x - (x - 1) / tab_width * tab_width)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn delete_back_char(self) -> Self {
         if self.cursor_position.x > 0 || self.cursor_position.y > 0 {
             let new_self = self.move_left();
             let document = new_self.document.delete(&new_self.cursor_position);
@@ -530,10 +1172,86 @@ impl Inner {
         }
     }
 
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            document: self.document.clone(),
+            cursor_position: self.cursor_position,
+        }
+    }
+
+    /// Apply an editing operation, recording the previous state for undo.
+    ///
+    /// Consecutive character inserts are grouped into a single undo step.
+    fn edit(self, insert: bool, f: impl FnOnce(Self) -> Self) -> Self {
+        if insert && self.coalesce_insert {
+            // The undo entry pushed by the first insert of the group covers this one.
+            return f(self);
+        }
+        let before = self.snapshot();
+        let mut new_self = f(self);
+        if new_self.document == before.document {
+            new_self.coalesce_insert = false;
+            return new_self;
+        }
+        new_self.undo_stack.push_back(before);
+        if new_self.undo_stack.len() > MAX_UNDO {
+            new_self.undo_stack.pop_front();
+        }
+        new_self.redo_stack.clear();
+        new_self.coalesce_insert = insert;
+        new_self
+    }
+
+    fn restore(self, snapshot: Snapshot) -> Self {
+        let Snapshot {
+            document,
+            cursor_position,
+        } = snapshot;
+        let cursor = Self::set_cursor_char(cursor_position, self.cursor, document.rows());
+        Self {
+            document,
+            cursor,
+            cursor_position,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn undo(mut self) -> Self {
+        let Some(snapshot) = self.undo_stack.pop_back() else {
+            return self;
+        };
+        let current = self.snapshot();
+        self.redo_stack.push(current);
+        self.restore(snapshot)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn redo(mut self) -> Self {
+        let Some(snapshot) = self.redo_stack.pop() else {
+            return self;
+        };
+        let current = self.snapshot();
+        self.undo_stack.push_back(current);
+        self.restore(snapshot)
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn scroll(self) -> Self {
+        if self.soft_wrap {
+            let line = self.cursor_display_line(&self.display_lines());
+            let height = self.height as usize;
+            let mut offset = Position::new(0, self.offset.y);
+            if line < offset.y {
+                offset.y = line;
+            } else if line >= offset.y.saturating_add(height) {
+                offset.y = line.saturating_sub(height).saturating_add(1);
+            }
+            return Self { offset, ..self };
+        }
         let Position { x, y } = self.cursor_position;
-        let width = self.width as usize - 4;
+        // Keep at least one column so the cursor stays visible in tiny textareas.
+        let width = self.text_width().max(1);
         let height = self.height as usize;
         let mut offset = self.offset;
         if y < offset.y {
@@ -569,18 +1287,39 @@ impl Model for Inner {
         let old_cursor = self.cursor_position;
         let new_self = if let Some(event) = msg.downcast_ref::<KeyEvent>() {
             let key = self.key_bindings.0.get(matcha::Key::from(event));
-            match key {
+            let insert = key.is_none() && matches!(event.code, KeyCode::Char(_));
+            let rejected = match key {
+                Some(TextareaKeys::InsertNewline) => !self.can_insert_newline(),
+                _ => insert && !self.can_insert(),
+            };
+            if rejected {
+                cmds.push(Cmd::sync(Box::new(|| Box::new(LimitReachedMsg))));
+            }
+            let new_self = match key {
                 Some(TextareaKeys::MoveLeft) => self.move_left(),
                 Some(TextareaKeys::MoveRight) => self.move_right(),
                 Some(TextareaKeys::MoveUp) => self.move_up(),
                 Some(TextareaKeys::MoveDown) => self.move_down(),
-                Some(TextareaKeys::InsertNewline) => self.insert_newline(),
-                Some(TextareaKeys::DeleteBack) => self.delete_back(),
-                Some(TextareaKeys::DeleteForward) => self.delete_forward(),
-                _ => match event.code {
-                    KeyCode::Char(char) => self.insert(char),
+                Some(TextareaKeys::InsertNewline) => self.edit(false, Self::insert_newline),
+                Some(TextareaKeys::DeleteBack) => self.edit(false, Self::delete_back),
+                Some(TextareaKeys::DeleteForward) => self.edit(false, Self::delete_forward),
+                Some(TextareaKeys::LineStart) => self.move_to_line_start(),
+                Some(TextareaKeys::LineEnd) => self.move_to_line_end(),
+                Some(TextareaKeys::WordLeft) => self.move_word_left(),
+                Some(TextareaKeys::WordRight) => self.move_word_right(),
+                Some(TextareaKeys::Undo) => self.undo(),
+                Some(TextareaKeys::Redo) => self.redo(),
+                Some(TextareaKeys::KillToLineEnd) => self.edit(false, Self::kill_to_line_end),
+                Some(TextareaKeys::KillToLineStart) => self.edit(false, Self::kill_to_line_start),
+                Some(TextareaKeys::Yank) => self.edit(false, Self::yank),
+                None => match event.code {
+                    KeyCode::Char(char) => self.edit(true, |s| s.insert(char)),
                     _ => self,
                 },
+            };
+            Self {
+                coalesce_insert: insert && new_self.coalesce_insert,
+                ..new_self
             }
         } else {
             self
@@ -597,7 +1336,7 @@ impl Model for Inner {
 
         let (cursor, cmd) = new_cursor.update(msg);
         cmd.into_iter().for_each(|c| cmds.push(c));
-        (Self { cursor, ..new_self }, Some(matcha::batch(cmds)))
+        (Self { cursor, ..new_self }, matcha::cmds(cmds))
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -654,4 +1393,469 @@ mod tests {
             "comment lines should include ANSI style sequences"
         );
     }
+
+    #[test]
+    fn render_row_expands_tabs_to_tab_stops() {
+        let inner = Inner::with_content("a\tb\nx")
+            .show_line_numbers(false)
+            .size(20, 2);
+        let rendered = inner.render_row(inner.document.row(0).expect("row"), 0);
+        assert!(rendered.ends_with("   b"), "got {rendered:?}");
+
+        // The cursor sits on the tab and the rest of the expansion follows it.
+        let cursor_position = Position::new(1, 0);
+        let cursor = Inner::set_cursor_char(cursor_position, inner.cursor, inner.document.rows());
+        let inner = Inner {
+            cursor_position,
+            cursor,
+            ..inner
+        };
+        let rendered = inner.render_row(inner.document.row(0).expect("row"), 0);
+        assert_eq!(matcha::remove_escape_sequences(&rendered), "a   b");
+        assert_eq!(inner.move_right().cursor_position, Position::new(2, 0));
+    }
+
+    fn soft_tab_row(content: &str, x: usize) -> Inner {
+        Inner {
+            cursor_position: Position::new(x, 0),
+            ..Inner::with_content(content).soft_tabs(true)
+        }
+    }
+
+    #[test]
+    fn delete_back_removes_soft_tab_indent() {
+        let inner = soft_tab_row("    foo", 4).delete_back();
+        assert_eq!(inner.document.row(0).unwrap().as_str(), "foo");
+        assert_eq!(inner.cursor_position, Position::new(0, 0));
+
+        // Back to the previous tab stop only.
+        let inner = soft_tab_row("      foo", 6).delete_back();
+        assert_eq!(inner.document.row(0).unwrap().as_str(), "    foo");
+
+        // Outside leading whitespace a single character is deleted.
+        let inner = soft_tab_row("    foo", 6).delete_back();
+        assert_eq!(inner.document.row(0).unwrap().as_str(), "    fo");
+    }
+
+    #[test]
+    fn insert_combining_mark_keeps_single_grapheme() {
+        let inner = Inner {
+            document: Document::with_rows(vec![Row::from("")]),
+            ..Inner::new()
+        };
+        let inner = inner.insert('e').insert('\u{301}');
+        let row = inner.document.row(0).expect("row");
+        assert_eq!(
+            row.as_str().graphemes(true).collect::<Vec<_>>(),
+            vec!["e\u{301}"]
+        );
+        assert_eq!(inner.cursor_position, Position::new(1, 0));
+    }
+
+    fn wrapped_row_at(x: usize, soft_wrap: bool) -> Inner {
+        // Text width is 10: "the quick " | "brown fox " | "jumps"
+        let inner = Inner::with_content("the quick brown fox jumps")
+            .show_line_numbers(false)
+            .size(10, 3);
+        Inner {
+            cursor_position: Position::new(x, 0),
+            soft_wrap,
+            ..inner
+        }
+    }
+
+    #[test]
+    fn line_start_and_end_use_visual_line_with_soft_wrap() {
+        let start = wrapped_row_at(13, true).move_to_line_start();
+        assert_eq!(start.cursor_position, Position::new(10, 0));
+
+        let end = wrapped_row_at(13, true).move_to_line_end();
+        assert_eq!(end.cursor_position, Position::new(19, 0));
+
+        let end = wrapped_row_at(21, true).move_to_line_end();
+        assert_eq!(end.cursor_position, Position::new(25, 0));
+    }
+
+    #[test]
+    fn line_start_and_end_use_logical_row_without_soft_wrap() {
+        let start = wrapped_row_at(13, false).move_to_line_start();
+        assert_eq!(start.cursor_position, Position::new(0, 0));
+
+        let end = wrapped_row_at(13, false).move_to_line_end();
+        assert_eq!(end.cursor_position, Position::new(25, 0));
+    }
+
+    fn type_text(mut inner: Inner, text: &str) -> Inner {
+        for c in text.chars() {
+            let key: matcha::Msg =
+                Box::new(KeyEvent::new(KeyCode::Char(c), matcha::KeyModifiers::NONE));
+            inner = inner.update(&key).0;
+        }
+        inner
+    }
+
+    #[test]
+    fn tiny_sizes_render_without_panicking() {
+        let inner = type_text(Inner::with_content("x").size(2, 0), "hello");
+        assert_eq!(inner.view().to_string(), "");
+
+        let inner = type_text(Inner::with_content("x").size(2, 1), "hello");
+        let view = inner.view().to_string();
+        assert!(!view.contains('1'), "gutter should be dropped: {view:?}");
+
+        let textarea = Textarea::new().size(2, 0);
+        let _ = textarea.view().to_string();
+    }
+
+    #[test]
+    fn empty_document_renders_dimmed_placeholder() {
+        let inner = Inner::with_content("").placeholder("Type here").size(20, 2);
+        let rendered = inner.render_rows();
+        assert!(rendered.contains(&style("Type here".to_string()).dim().to_string()));
+        assert_eq!(
+            matcha::remove_escape_sequences(&rendered),
+            "  1 Type here\n  ~"
+        );
+
+        // The placeholder is clamped to the text width.
+        let inner = Inner::with_content("").placeholder("Type here").size(8, 1);
+        assert_eq!(
+            matcha::remove_escape_sequences(&inner.render_rows()),
+            "  1 Type"
+        );
+    }
+
+    #[test]
+    fn typing_clears_placeholder() {
+        let (inner, _) = Inner::with_content("")
+            .placeholder("Type here")
+            .size(20, 2)
+            .focus();
+        let inner = type_text(inner, "a");
+        let rendered = matcha::remove_escape_sequences(&inner.render_rows());
+        assert!(!rendered.contains("Type here"), "got {rendered:?}");
+        assert!(rendered.starts_with("  1 a"), "got {rendered:?}");
+    }
+
+    #[test]
+    fn set_value_replaces_text_and_clamps_cursor() {
+        let inner = Inner {
+            cursor_position: Position::new(5, 2),
+            ..Inner::with_content("hello\nworld\nagain").size(20, 3)
+        };
+        assert_eq!(inner.value(), "hello\nworld\nagain");
+
+        let inner = inner.set_value("ab\ncd");
+        assert_eq!(inner.value(), "ab\ncd");
+        assert_eq!(inner.cursor_position, Position::new(2, 1));
+
+        let inner = inner.set_value("");
+        assert_eq!(inner.value(), "");
+        assert_eq!(inner.document.len(), 1);
+        assert_eq!(inner.cursor_position, Position::new(0, 0));
+    }
+
+    #[test]
+    fn set_value_keeps_trailing_empty_lines() {
+        let inner = Inner::default().set_value("a\n");
+        assert_eq!(inner.value(), "a\n");
+        assert_eq!(inner.document.len(), 2);
+
+        let inner = inner.set_value("\n");
+        assert_eq!(inner.value(), "\n");
+        assert_eq!(inner.document.len(), 2);
+
+        let inner = inner.set_value("one\r\ntwo\r\n");
+        assert_eq!(inner.value(), "one\ntwo\n");
+        assert_eq!(Inner::with_content("x\n\n").document.len(), 3);
+    }
+
+    #[test]
+    fn textarea_value_round_trips_through_border() {
+        let textarea = Textarea::new().border().set_value("one\ntwo");
+        assert_eq!(textarea.value(), "one\ntwo");
+        assert_eq!(Textarea::new().value(), "");
+    }
+
+    fn press(inner: Inner, key: KeyEvent) -> Inner {
+        let msg: matcha::Msg = Box::new(key);
+        inner.update(&msg).0
+    }
+
+    #[test]
+    fn undo_reverts_typed_word_as_one_step_and_redo_reapplies() {
+        let undo = KeyEvent::new(KeyCode::Char('z'), matcha::KeyModifiers::CONTROL);
+        let redo = KeyEvent::new(KeyCode::Char('y'), matcha::KeyModifiers::CONTROL);
+        let enter = KeyEvent::new(KeyCode::Enter, matcha::KeyModifiers::NONE);
+
+        let inner = type_text(Inner::with_content("").size(20, 3), "ab");
+        let inner = type_text(press(inner, enter), "cd");
+        assert_eq!(inner.value(), "ab\ncd");
+
+        let inner = press(inner, undo);
+        assert_eq!(inner.value(), "ab\n");
+        let inner = press(inner, undo);
+        assert_eq!(inner.value(), "ab");
+        assert_eq!(inner.cursor_position, Position::new(2, 0));
+        let inner = press(inner, undo);
+        assert_eq!(inner.value(), "");
+        // Nothing left to undo.
+        let inner = press(inner, undo);
+        assert_eq!(inner.value(), "");
+
+        let inner = press(press(inner, redo), redo);
+        assert_eq!(inner.value(), "ab\n");
+        assert_eq!(inner.cursor_position, Position::new(0, 1));
+
+        // A new edit drops the redo history.
+        let inner = press(type_text(inner, "x"), redo);
+        assert_eq!(inner.value(), "ab\nx");
+    }
+
+    #[test]
+    fn cursor_moves_split_insert_groups() {
+        let undo = KeyEvent::new(KeyCode::Char('z'), matcha::KeyModifiers::CONTROL);
+        let left = KeyEvent::new(KeyCode::Left, matcha::KeyModifiers::NONE);
+        let inner = type_text(Inner::with_content("").size(20, 1), "ac");
+        let inner = type_text(press(inner, left), "b");
+        assert_eq!(inner.value(), "abc");
+        assert_eq!(press(inner, undo).value(), "ac");
+    }
+
+    #[test]
+    fn undo_history_is_bounded() {
+        let backspace = KeyEvent::new(KeyCode::Backspace, matcha::KeyModifiers::NONE);
+        let mut inner = Inner::with_content("x".repeat(MAX_UNDO + 10)).size(20, 1);
+        inner.cursor_position = Position::new(MAX_UNDO + 10, 0);
+        for _ in 0..MAX_UNDO + 10 {
+            inner = press(inner, backspace);
+        }
+        assert_eq!(inner.undo_stack.len(), MAX_UNDO);
+    }
+
+    #[test]
+    fn consecutive_inserts_push_a_single_undo_entry() {
+        let inner = type_text(Inner::with_content("").size(20, 1), "hello world");
+        assert_eq!(inner.undo_stack.len(), 1);
+        assert_eq!(inner.undo_stack[0].document.rows()[0].as_str(), "");
+    }
+
+    fn soft_wrapped(x: usize) -> Inner {
+        // Text width is 10: "the quick " | "brown fox " | "jumps"
+        Inner {
+            cursor_position: Position::new(x, 0),
+            ..Inner::with_content("the quick brown fox jumps\nend")
+                .size(14, 3)
+                .soft_wrap(true)
+        }
+    }
+
+    #[test]
+    fn soft_wrap_renders_display_lines_with_row_numbers() {
+        let rendered = matcha::remove_escape_sequences(&soft_wrapped(0).render_rows());
+        let lines: Vec<_> = rendered.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["  1 the quick", "    brown fox", "    jumps"]);
+    }
+
+    #[test]
+    fn soft_wrap_measures_tabs_like_rendering() {
+        // Text width is 8: "a" and a tab reach column 4, "b" and a tab column 8.
+        let inner = Inner::with_content("a\tb\tc").size(12, 2).soft_wrap(true);
+        let rendered = matcha::remove_escape_sequences(&inner.render_rows());
+        let lines: Vec<_> = rendered.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["  1 a   b", "    c"]);
+    }
+
+    #[test]
+    fn soft_wrap_keeps_rendered_lines_within_the_width() {
+        // Text width is 10: the run of spaces spills onto the second line with "b".
+        let content = "aaaaaaaaa          b\nthe quick brown fox jumps";
+        for (y, len) in [(0, 20), (1, 25)] {
+            for x in 0..=len {
+                let inner = Inner {
+                    cursor_position: Position::new(x, y),
+                    ..Inner::with_content(content).size(14, 8).soft_wrap(true)
+                };
+                let (inner, _) = inner.focus();
+                let rendered = matcha::remove_escape_sequences(&inner.render_rows());
+                for line in rendered.lines() {
+                    assert!(line.width() <= 14, "{line:?} with the cursor at {x}, {y}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn soft_wrap_moves_and_scrolls_by_display_line() {
+        // From column 2 of "the quick " to column 2 of "brown fox ".
+        let inner = soft_wrapped(2).move_down();
+        assert_eq!(inner.cursor_position, Position::new(12, 0));
+        // "jumps" is shorter than column 8, so the cursor lands at its end.
+        let inner = Inner {
+            cursor_position: Position::new(18, 0),
+            ..inner
+        }
+        .move_down();
+        assert_eq!(inner.cursor_position, Position::new(25, 0));
+        let inner = inner.move_down().scroll();
+        assert_eq!(inner.cursor_position, Position::new(3, 1));
+        assert_eq!(inner.offset, Position::new(0, 1));
+        let rendered = matcha::remove_escape_sequences(&inner.render_rows());
+        assert!(rendered.ends_with("  2 end"), "got {rendered:?}");
+
+        let inner = inner.move_up().move_up();
+        assert_eq!(inner.cursor_position, Position::new(13, 0));
+    }
+
+    #[test]
+    fn gutter_grows_with_line_count_and_hides_when_disabled() {
+        let content = vec!["x"; 1000].join("\n");
+        let inner = Inner::with_content(content.clone()).size(10, 1);
+        assert_eq!(inner.line_number_width(), 5);
+        assert_eq!(inner.text_width(), 5);
+        assert_eq!(
+            matcha::remove_escape_sequences(&inner.render_rows()),
+            "   1 x"
+        );
+
+        let inner = Inner::with_content("0123456789")
+            .show_line_numbers(false)
+            .size(10, 1);
+        assert_eq!(inner.text_width(), 10);
+        let rendered = matcha::remove_escape_sequences(&inner.render_rows());
+        assert!(rendered.starts_with("0123456789"), "got {rendered:?}");
+    }
+
+    #[test]
+    fn limits_reject_input_and_send_limit_reached() {
+        let enter = KeyEvent::new(KeyCode::Enter, matcha::KeyModifiers::NONE);
+        let inner = Inner::with_content("")
+            .char_limit(Some(3))
+            .max_lines(Some(2))
+            .size(20, 3);
+        let inner = type_text(inner, "あいうえ");
+        assert_eq!(inner.value(), "あいう");
+
+        let inner = press(inner, enter);
+        assert_eq!(inner.document.len(), 2);
+        let msg: matcha::Msg = Box::new(enter);
+        let (inner, cmd) = inner.update(&msg);
+        assert_eq!(inner.document.len(), 2);
+        assert!(sync_msgs(cmd).iter().any(|msg| msg.is::<LimitReachedMsg>()));
+    }
+
+    fn sync_msgs(cmd: Option<Cmd>) -> Vec<matcha::Msg> {
+        match cmd {
+            None => vec![],
+            Some(Cmd::Sync(matcha::SyncCmd(f))) => {
+                let msg = f();
+                match msg.downcast::<matcha::BatchMsg>() {
+                    Ok(batch) => batch.into_iter().flat_map(|c| sync_msgs(Some(c))).collect(),
+                    Err(msg) => vec![msg],
+                }
+            }
+            Some(Cmd::Async(_)) => panic!("unexpected async command"),
+        }
+    }
+
+    #[test]
+    fn word_movement_steps_over_multibyte_words_and_rows() {
+        let word_left = KeyEvent::new(KeyCode::Left, matcha::KeyModifiers::CONTROL);
+        let word_right = KeyEvent::new(KeyCode::Char('f'), matcha::KeyModifiers::ALT);
+        let inner = Inner::with_content("日本語 テキスト, héllo\nnext").size(40, 2);
+
+        let mut positions = vec![];
+        let mut inner = inner;
+        for _ in 0..5 {
+            inner = press(inner, word_right);
+            positions.push(inner.cursor_position);
+        }
+        assert_eq!(
+            positions,
+            vec![
+                Position::new(3, 0),
+                Position::new(8, 0),
+                Position::new(15, 0),
+                Position::new(0, 1),
+                Position::new(4, 1),
+            ]
+        );
+
+        let mut positions = vec![];
+        for _ in 0..4 {
+            inner = press(inner, word_left);
+            positions.push(inner.cursor_position);
+        }
+        assert_eq!(
+            positions,
+            vec![
+                Position::new(0, 1),
+                Position::new(15, 0),
+                Position::new(10, 0),
+                Position::new(4, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn ctrl_a_and_ctrl_e_jump_to_line_bounds() {
+        let ctrl_a = KeyEvent::new(KeyCode::Char('a'), matcha::KeyModifiers::CONTROL);
+        let ctrl_e = KeyEvent::new(KeyCode::Char('e'), matcha::KeyModifiers::CONTROL);
+        let inner = press(Inner::with_content("héllo wörld").size(20, 1), ctrl_e);
+        assert_eq!(inner.cursor_position, Position::new(11, 0));
+        assert_eq!(press(inner, ctrl_a).cursor_position, Position::new(0, 0));
+    }
+
+    #[test]
+    fn typing_into_an_empty_document_starts_a_row() {
+        let left = KeyEvent::new(KeyCode::Left, matcha::KeyModifiers::NONE);
+        let inner = press(Inner::with_content("").size(20, 1), left);
+        let inner = type_text(inner, "hi");
+        assert_eq!(inner.value(), "hi");
+        assert_eq!(inner.cursor_position, Position::new(2, 0));
+    }
+
+    #[test]
+    fn kill_and_yank_move_text_through_the_kill_buffer() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), matcha::KeyModifiers::CONTROL);
+        let yank = KeyEvent::new(KeyCode::Char('y'), matcha::KeyModifiers::ALT);
+        let inner = Inner {
+            cursor_position: Position::new(3, 0),
+            ..Inner::with_content("héllo wörld\nnext").size(20, 2)
+        };
+
+        let inner = press(inner, ctrl('k'));
+        assert_eq!(inner.value(), "hél\nnext");
+        // At the end of the line the next row is joined.
+        let inner = press(inner, ctrl('k'));
+        assert_eq!(inner.value(), "hélnext");
+        assert_eq!(inner.kill_buffer, "\n");
+        let inner = press(inner, yank);
+        assert_eq!(inner.value(), "hél\nnext");
+        assert_eq!(inner.cursor_position, Position::new(0, 1));
+
+        let inner = press(press(inner, ctrl('e')), ctrl('u'));
+        assert_eq!(inner.value(), "hél\n");
+        assert_eq!(inner.cursor_position, Position::new(0, 1));
+        let inner = press(press(inner, yank), yank);
+        assert_eq!(inner.value(), "hél\nnextnext");
+        assert_eq!(inner.cursor_position, Position::new(8, 1));
+    }
+
+    #[test]
+    fn textarea_reports_cursor_line_and_column() {
+        let textarea = Textarea::with_content("日本\nhéllo").border();
+        assert_eq!(textarea.cursor_position(), (1, 1));
+        assert_eq!(textarea.line_count(), 2);
+
+        let down = KeyEvent::new(KeyCode::Down, matcha::KeyModifiers::NONE);
+        let end = KeyEvent::new(KeyCode::End, matcha::KeyModifiers::NONE);
+        let mut textarea = textarea;
+        for key in [down, end] {
+            let msg: matcha::Msg = Box::new(key);
+            textarea = textarea.update(&msg).0;
+        }
+        assert_eq!(textarea.cursor_position(), (2, 6));
+        assert_eq!(Textarea::new().line_count(), 1);
+    }
 }
@@ -1,7 +1,7 @@
-use std::cmp;
+use std::{cmp, ops::Range};
 use unicode_segmentation::UnicodeSegmentation;
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, PartialEq)]
 /// A single line of text stored as graphemes.
 pub struct Row {
     string: String,
@@ -29,13 +29,7 @@ impl Row {
             .skip(start)
             .take(end - start)
         {
-            if let Some(c) = grapheme.chars().next() {
-                if c == '\t' {
-                    result += " ";
-                } else {
-                    result.push(c);
-                }
-            }
+            result.push_str(grapheme);
         }
         result
     }
@@ -48,24 +42,17 @@ impl Row {
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Insert a character at grapheme index `at`.
+    ///
+    /// The row is re-segmented afterwards, so a combining mark merges with the grapheme
+    /// before it instead of becoming a grapheme of its own.
     pub fn insert(&mut self, at: usize, c: char) {
-        if at >= self.len() {
-            self.string.push(c);
-            self.len += 1;
-            return;
-        }
-        let mut result: String = String::new();
-        let mut length = 0;
-        for (index, grapheme) in self.string[..].graphemes(true).enumerate() {
-            length += 1;
-            if index == at {
-                length += 1;
-                result.push(c);
-            }
-            result.push_str(grapheme);
-        }
-        self.len = length;
-        self.string = result;
+        let byte = self
+            .string
+            .grapheme_indices(true)
+            .nth(at)
+            .map_or(self.string.len(), |(index, _)| index);
+        self.string.insert(byte, c);
+        self.len = self.string.graphemes(true).count();
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -123,6 +110,86 @@ impl Row {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Split the row into visual lines of at most `width` columns, breaking at whitespace.
+    ///
+    /// Each range holds grapheme indices. Whitespace at a break stays at the end of the
+    /// previous range until the line reaches `width`; the rest of the run starts the next
+    /// range. Only words wider than `width` are broken mid-word. A tab
+    /// reaches the next multiple of `tab_width` columns of its visual line; a `tab_width`
+    /// of `0` makes tabs take no space.
+    pub fn wrap_ranges(&self, width: usize, tab_width: usize) -> Vec<Range<usize>> {
+        let graphemes: Vec<&str> = self.string.graphemes(true).collect();
+        let is_space = |g: &str| g.chars().all(char::is_whitespace);
+        // The column after `g` when it starts at `column`. A tab reaches the next tab stop
+        // of the display line, matching how each wrapped line is rendered.
+        let advance = |column: usize, g: &&str| match *g {
+            "\t" if tab_width == 0 => column,
+            "\t" => column + tab_width - column % tab_width,
+            g => column + matcha::display_width(g) as usize,
+        };
+        let mut starts = vec![0];
+        let mut line_width = 0;
+        let mut i = 0;
+        while i < graphemes.len() && width > 0 {
+            while i < graphemes.len() && is_space(graphemes[i]) {
+                let next = advance(line_width, &graphemes[i]);
+                if line_width > 0 && next > width {
+                    starts.push(i);
+                    line_width = advance(0, &graphemes[i]);
+                } else {
+                    line_width = next;
+                }
+                i += 1;
+            }
+            let word_start = i;
+            while i < graphemes.len() && !is_space(graphemes[i]) {
+                i += 1;
+            }
+            let word_end = graphemes[word_start..i].iter().fold(line_width, advance);
+            if word_start < i && line_width > 0 && word_end > width {
+                starts.push(word_start);
+                line_width = 0;
+            }
+            for (index, grapheme) in graphemes.iter().enumerate().take(i).skip(word_start) {
+                let next = advance(line_width, grapheme);
+                if line_width > 0 && next > width {
+                    starts.push(index);
+                    line_width = advance(0, grapheme);
+                } else {
+                    line_width = next;
+                }
+            }
+        }
+        let ends = starts.iter().skip(1).copied().chain([self.len]);
+        starts
+            .iter()
+            .copied()
+            .zip(ends)
+            .map(|(s, e)| s..e)
+            .collect()
+    }
+
+    /// Grapheme ranges of the words in the row, per Unicode word boundaries.
+    ///
+    /// Segments without an alphanumeric character (spaces, punctuation) are skipped, and
+    /// adjacent segments are joined, since ideographs are split one per segment.
+    pub fn word_ranges(&self) -> Vec<Range<usize>> {
+        let mut start = 0;
+        let mut words: Vec<Range<usize>> = vec![];
+        for segment in self.string.split_word_bounds() {
+            let end = start + segment.graphemes(true).count();
+            if segment.chars().any(char::is_alphanumeric) {
+                match words.last_mut() {
+                    Some(word) if word.end == start => word.end = end,
+                    _ => words.push(start..end),
+                }
+            }
+            start = end;
+        }
+        words
+    }
+
     // pub fn as_bytes(&self) -> &[u8] {
     //     self.string.as_bytes()
     // }
@@ -168,6 +235,60 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn insert_combining_mark_merges_with_previous_grapheme() {
+        let mut row = Row::from("");
+        row.insert(0, 'e');
+        row.insert(1, '\u{301}');
+        assert_eq!(
+            row.as_str().graphemes(true).collect::<Vec<_>>(),
+            vec!["e\u{301}"]
+        );
+        assert_eq!(row.len(), 1);
+    }
+
+    #[test]
+    fn word_ranges_count_graphemes() {
+        let row = Row::from("héllo, wörld e\u{301}! 日本語");
+        assert_eq!(row.word_ranges(), vec![0..5, 7..12, 13..14, 16..19]);
+    }
+
+    #[test]
+    fn wrap_ranges_break_at_whitespace() {
+        let row = Row::from("the quick brown fox");
+        assert_eq!(row.wrap_ranges(10, 4), vec![0..10, 10..19]);
+    }
+
+    #[test]
+    fn wrap_ranges_hard_break_long_words() {
+        let row = Row::from("abcdefgh");
+        assert_eq!(row.wrap_ranges(3, 4), vec![0..3, 3..6, 6..8]);
+    }
+
+    #[test]
+    fn wrap_ranges_measure_tabs_to_the_next_stop() {
+        // "a" ends at 1, the tab at 4 and "b" at 5; the next tab reaches 8, so "c" wraps.
+        let row = Row::from("a\tb\tc");
+        assert_eq!(row.wrap_ranges(8, 4), vec![0..4, 4..5]);
+        assert_eq!(row.wrap_ranges(8, 0), vec![0..5]);
+    }
+
+    #[test]
+    fn wrap_ranges_stop_adding_break_whitespace_at_the_width() {
+        // "aaaaaaaaa" and one space fill the line; the other nine spaces and "b" fit on
+        // the next line.
+        let row = Row::from("aaaaaaaaa          b");
+        assert_eq!(row.wrap_ranges(10, 4), vec![0..10, 10..20]);
+        let row = Row::from("ab       ");
+        assert_eq!(row.wrap_ranges(3, 4), vec![0..3, 3..6, 6..9]);
+    }
+
+    #[test]
+    fn wrap_ranges_measure_emoji_presentation_as_two_columns() {
+        let row = Row::from("ab\u{2764}\u{fe0f}c");
+        assert_eq!(row.wrap_ranges(3, 4), vec![0..2, 2..4]);
+    }
+
     proptest! {
         #[test]
         fn insert_matches_reference(
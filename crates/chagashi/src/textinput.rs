@@ -1,12 +1,39 @@
 use std::fmt::Display;
 
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use matcha::{batch, Cmd, Color, KeyCode, KeyEvent, KeyModifiers, Model, Msg, Stylize};
+use matcha::{Cmd, Color, CursorShape, KeyCode, KeyEvent, KeyModifiers, Model, Msg, Stylize};
 
 use crate::cursor;
 use crate::utils::*;
 
+/// How a [`TextInput`] displays its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EchoMode {
+    /// Show the value as typed.
+    #[default]
+    Normal,
+    /// Show every grapheme of the value as the given mask character.
+    Password(char),
+    /// Show nothing but the prompt and the cursor.
+    None,
+}
+
+#[derive(Debug)]
+/// Message emitted when Enter is pressed in a focused [`TextInput`], see
+/// [`TextInput::with_submit_on_enter`].
+pub struct TextInputSubmitMsg {
+    /// The value at the time of submission.
+    pub value: String,
+}
+
+/// Decides whether a candidate value is accepted, see [`TextInput::validate`].
+type Validate = Box<dyn Fn(&str) -> bool + Send>;
+
+/// Decides whether a typed character is inserted, see [`TextInput::char_filter`].
+type CharFilter = Box<dyn Fn(char) -> bool + Send>;
+
 /// A single-line text input component.
 ///
 /// This widget tracks a cursor position and handles basic editing keys.
@@ -17,6 +44,16 @@ pub struct TextInput {
     value: String,
     focus: bool,
     pos: usize,
+    echo_mode: EchoMode,
+    validate: Option<Validate>,
+    char_filter: Option<CharFilter>,
+    char_limit: Option<usize>,
+    // Columns available to the value; 0 means unbounded.
+    width: u16,
+    // Grapheme index of the first visible grapheme when the value is wider than `width`.
+    offset: usize,
+    submit_on_enter: bool,
+    suggestions: Vec<String>,
 }
 
 impl Default for TextInput {
@@ -28,6 +65,14 @@ impl Default for TextInput {
             value: String::default(),
             focus: false,
             pos: 0,
+            echo_mode: EchoMode::default(),
+            validate: None,
+            char_filter: None,
+            char_limit: None,
+            width: 0,
+            offset: 0,
+            submit_on_enter: false,
+            suggestions: vec![],
         }
     }
 }
@@ -79,6 +124,78 @@ impl TextInput {
         )
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set the hardware cursor shape requested while the input is focused.
+    pub fn cursor_shape(self, shape: CursorShape) -> Self {
+        Self {
+            cursor: self.cursor.shape(shape),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Set how the value is displayed, e.g. masked for passwords.
+    ///
+    /// Only the view changes; [`TextInput::value`] still returns the typed text.
+    pub fn echo_mode(self, echo_mode: EchoMode) -> Self {
+        let cursor = self
+            .cursor
+            .set_char(echo_char(echo_mode, &self.value, self.pos));
+        Self {
+            cursor,
+            echo_mode,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Check every edit: an insert or delete whose resulting value fails `validate` is
+    /// rejected and the value stays unchanged.
+    pub fn validate(self, validate: impl Fn(&str) -> bool + Send + 'static) -> Self {
+        Self {
+            validate: Some(Box::new(validate)),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Only insert typed characters accepted by `filter`, e.g. `char::is_ascii_digit`.
+    pub fn char_filter(self, filter: impl Fn(char) -> bool + Send + 'static) -> Self {
+        Self {
+            char_filter: Some(Box::new(filter)),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Stop inserting once the value has `limit` graphemes.
+    pub fn char_limit(self, limit: usize) -> Self {
+        Self {
+            char_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Graphemes left before the [`TextInput::char_limit`], or `None` without a limit.
+    pub fn remaining(&self) -> Option<usize> {
+        let count = self.value.graphemes(true).count();
+        self.char_limit.map(|limit| limit.saturating_sub(count))
+    }
+
+    /// Whether the current value passes [`TextInput::validate`].
+    ///
+    /// Always true without a validator. A value set with [`TextInput::set_value`] is not
+    /// checked, so this is useful for styling.
+    pub fn is_valid(&self) -> bool {
+        self.accepts(&self.value)
+    }
+
+    fn accepts(&self, value: &str) -> bool {
+        self.validate
+            .as_ref()
+            .map_or(true, |validate| validate(value))
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Replace the internal cursor model.
     pub fn set_cursor(self, cursor: cursor::Cursor) -> Self {
@@ -93,6 +210,7 @@ impl TextInput {
             pos: std::cmp::min(pos, max),
             ..self
         }
+        .scroll()
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -102,6 +220,160 @@ impl TextInput {
             value: value.into(),
             ..self
         }
+        .scroll()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Send a [`TextInputSubmitMsg`] when Enter is pressed. Disabled by default.
+    pub fn with_submit_on_enter(self, submit_on_enter: bool) -> Self {
+        Self {
+            submit_on_enter,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Offer completions: while the cursor is at the end of a value that prefixes a
+    /// suggestion, the rest of it is shown dimmed after the cursor, and Tab or Right
+    /// accepts it.
+    pub fn set_suggestions(self, suggestions: Vec<String>) -> Self {
+        let new_self = Self {
+            suggestions,
+            ..self
+        };
+        match new_self.suggestion_rest().map(str::to_string) {
+            Some(rest) => new_self.suggestion_cursor(&rest),
+            None => new_self,
+        }
+    }
+
+    /// The untyped part of the first suggestion completing the value, if the cursor is
+    /// at the end of the value.
+    fn suggestion_rest(&self) -> Option<&str> {
+        if self.value.is_empty()
+            || self.echo_mode != EchoMode::Normal
+            || self.pos < self.value.graphemes(true).count()
+        {
+            return None;
+        }
+        self.suggestions
+            .iter()
+            .find_map(|s| s.strip_prefix(self.value.as_str()))
+            .filter(|rest| !rest.is_empty())
+    }
+
+    /// Show the first grapheme of a suggestion under the cursor.
+    fn suggestion_cursor(self, rest: &str) -> Self {
+        let c = rest.graphemes(true).next().unwrap_or(" ").to_string();
+        Self {
+            cursor: self.cursor.set_char(c),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Replace the value with the suggestion it prefixes.
+    fn accept_suggestion(self) -> Self {
+        let Some(rest) = self.suggestion_rest() else {
+            return self;
+        };
+        let value = self.value.clone() + rest;
+        let count = value.graphemes(true).count();
+        if self.char_limit.is_some_and(|limit| count > limit) || !self.accepts(&value) {
+            return self;
+        }
+        Self {
+            cursor: self.cursor.set_char(" "),
+            value,
+            pos: count,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Limit the value to `width` columns (excluding the prompt), scrolling horizontally
+    /// to keep the cursor visible. A width of 0 doesn't limit the value.
+    pub fn width(self, width: u16) -> Self {
+        Self { width, ..self }.scroll()
+    }
+
+    /// Move the visible window so the cursor stays inside it.
+    fn scroll(self) -> Self {
+        if self.width == 0 {
+            return Self { offset: 0, ..self };
+        }
+        let width = self.width as usize;
+        let widths: Vec<usize> = self
+            .echo_value()
+            .graphemes(true)
+            .map(|g| g.width())
+            .collect();
+        let pos = self.pos.min(widths.len());
+        // The cursor past the end takes one cell.
+        let cell = |i: usize| widths.get(i).copied().unwrap_or(1);
+        let mut offset = self.offset.min(pos);
+        while offset < pos && (offset..=pos).map(cell).sum::<usize>() > width {
+            offset += 1;
+        }
+        // Show more of the start when it fits, e.g. after deleting at the end.
+        let end = widths.len().max(pos + 1);
+        while offset > 0 && (offset - 1..end).map(cell).sum::<usize>() <= width {
+            offset -= 1;
+        }
+        Self { offset, ..self }
+    }
+
+    /// Cut a suggestion shown after the visible `value` and the cursor to the width.
+    fn clamp_ghost(&self, value: &str, ghost: String) -> String {
+        if self.width == 0 {
+            return ghost;
+        }
+        let mut used = value.width() + 1;
+        ghost
+            .graphemes(true)
+            .take_while(|g| {
+                used += g.width();
+                used <= self.width as usize
+            })
+            .collect()
+    }
+
+    /// The part of `value` inside the visible window and `pos` relative to it.
+    fn visible(&self, value: String, pos: usize) -> (String, usize) {
+        if self.width == 0 {
+            return (value, pos);
+        }
+        let mut used = 0;
+        let value = value
+            .graphemes(true)
+            .skip(self.offset)
+            .take_while(|g| {
+                used += g.width();
+                used <= self.width as usize
+            })
+            .collect();
+        (value, pos.saturating_sub(self.offset))
+    }
+
+    /// Return the input value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Clear the value and move the cursor to the start, e.g. after submitting.
+    ///
+    /// The cursor shows the placeholder again, as after [`TextInput::set_placeholder`].
+    pub fn reset(self) -> Self {
+        let placeholder = self.placeholder.clone();
+        Self {
+            cursor: self.cursor.set_char(" ").reset_text_color(),
+            value: String::default(),
+            pos: 0,
+            offset: 0,
+            ..self
+        }
+        .set_placeholder(placeholder)
     }
 
     /// cursor_start moves the cursor to the start of the input field.
@@ -133,8 +405,10 @@ impl TextInput {
         if self.pos >= self.value.graphemes(true).count() || !self.focus {
             return self;
         }
-        let value = self.value;
-        let value = remove_char(value, self.pos);
+        let value = remove_char(self.value.clone(), self.pos);
+        if !self.accepts(&value) {
+            return self;
+        }
         Self { value, ..self }
     }
 
@@ -144,12 +418,41 @@ impl TextInput {
         if self.pos == 0 || !self.focus {
             return self;
         }
-        let value = self.value;
         let pos = self.pos.saturating_sub(1);
-        let value = remove_char(value, pos);
+        let value = remove_char(self.value.clone(), pos);
+        if !self.accepts(&value) {
+            return self;
+        }
         Self { value, pos, ..self }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Insert a character at the cursor, unless the char filter or validator rejects it.
+    fn insert(self, c: char) -> Self {
+        if self.remaining() == Some(0)
+            || !self.char_filter.as_ref().map_or(true, |filter| filter(c))
+        {
+            return self;
+        }
+        let before = self.value.graphemes(true).count();
+        let value = insert_char(self.value.clone(), self.pos, c);
+        if !self.accepts(&value) {
+            return self;
+        }
+        // A combining mark merges into the previous grapheme, so the
+        // cursor advances by the number of graphemes actually added.
+        let pos = self.pos + value.graphemes(true).count().saturating_sub(before);
+
+        let c = echo_char(self.echo_mode, &value, pos);
+        let cursor = self.cursor.set_char(c).reset_text_color();
+        Self {
+            value,
+            cursor,
+            pos,
+            ..self
+        }
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     /// Move the cursor one grapheme to the left.
     pub fn move_left(self) -> Self {
@@ -157,13 +460,9 @@ impl TextInput {
             return self;
         }
         let pos = self.pos.saturating_sub(1);
-        let cursor = self.cursor.set_char(
-            self.value
-                .graphemes(true)
-                .nth(pos)
-                .unwrap_or(" ")
-                .to_string(),
-        );
+        let cursor = self
+            .cursor
+            .set_char(echo_char(self.echo_mode, &self.value, pos));
         Self { cursor, ..self }.set_pos(pos)
     }
 
@@ -177,16 +476,23 @@ impl TextInput {
             self.pos.saturating_add(1),
             self.value.graphemes(true).count(),
         );
-        let cursor = self.cursor.set_char(
-            self.value
-                .graphemes(true)
-                .nth(pos)
-                .unwrap_or(" ")
-                .to_string(),
-        );
+        let cursor = self
+            .cursor
+            .set_char(echo_char(self.echo_mode, &self.value, pos));
         Self { cursor, ..self }.set_pos(pos)
     }
 
+    /// The value as displayed under the current [`EchoMode`].
+    fn echo_value(&self) -> String {
+        match self.echo_mode {
+            EchoMode::Normal => self.value.clone(),
+            EchoMode::Password(mask) => std::iter::repeat(mask)
+                .take(self.value.graphemes(true).count())
+                .collect(),
+            EchoMode::None => String::default(),
+        }
+    }
+
     /// placeholderView returns the prompt and placeholder view, if any.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn placeholder_view(&self) -> String {
@@ -222,27 +528,16 @@ impl Model for TextInput {
                     KeyCode::Backspace => (self.delete_back_char(), None),
                     KeyCode::Delete => (self.delete_forward_char(), None),
                     KeyCode::Left => (self.move_left(), None),
+                    KeyCode::Right | KeyCode::Tab if self.suggestion_rest().is_some() => {
+                        (self.accept_suggestion(), None)
+                    }
                     KeyCode::Right => (self.move_right(), None),
-                    KeyCode::Char(char) => {
-                        let value = self.value;
-                        let value = insert_char(value, self.pos, char);
-
-                        let c = value
-                            .graphemes(true)
-                            .nth(self.pos + 1)
-                            .unwrap_or(" ")
-                            .to_string();
-                        let cursor = self.cursor.set_char(c).reset_text_color();
-                        let pos = std::cmp::min(value.graphemes(true).count(), self.pos + 1);
-                        (
-                            Self {
-                                value,
-                                cursor,
-                                pos,
-                                ..self
-                            },
-                            None,
-                        )
+                    KeyCode::Char(char) => (self.insert(char), None),
+                    KeyCode::Enter if self.submit_on_enter => {
+                        let value = self.value.clone();
+                        let cmd =
+                            Cmd::sync(Box::new(move || Box::new(TextInputSubmitMsg { value })));
+                        (self, Some(cmd))
                     }
                     _ => (self, None),
                 }
@@ -256,6 +551,10 @@ impl Model for TextInput {
             cmds.push(cmd);
         }
 
+        let new_self = match new_self.suggestion_rest().map(str::to_string) {
+            Some(rest) => new_self.suggestion_cursor(&rest),
+            None => new_self,
+        };
         let cur = if new_self.value.is_empty() && !new_self.placeholder.is_empty() {
             let c: String = new_self.placeholder.graphemes(true).next().unwrap().into();
             new_self
@@ -281,7 +580,7 @@ impl Model for TextInput {
         } else {
             cur
         };
-        (Self { cursor, ..new_self }, Some(batch(cmds)))
+        (Self { cursor, ..new_self }.scroll(), matcha::cmds(cmds))
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -289,14 +588,19 @@ impl Model for TextInput {
         if self.value.is_empty() && !self.placeholder.is_empty() {
             return self.placeholder_view();
         }
-        let value = self.value.clone();
+        let pos = if self.echo_mode == EchoMode::None {
+            0
+        } else {
+            self.pos
+        };
+        let (value, pos) = self.visible(self.echo_value(), pos);
 
-        if self.pos == 0 {
+        if pos == 0 {
             let (_, tail) = split_at(value, 1);
             return self.prompt.clone() + &format!("{}", self.cursor.view()) + &tail;
         }
-        if self.pos < self.value.graphemes(true).count() {
-            let (head, tail) = split_at(value, self.pos);
+        if pos < value.graphemes(true).count() {
+            let (head, tail) = split_at(value, pos);
             let tail = if tail.is_empty() {
                 tail
             } else {
@@ -308,19 +612,36 @@ impl Model for TextInput {
         }
 
         if self.focus {
-            self.prompt.clone() + &self.value + &format!("{}", self.cursor.view())
+            let ghost = match self.suggestion_rest() {
+                Some(rest) => {
+                    let (_, rest) = split_at(rest.to_string(), 1);
+                    let rest = self.clamp_ghost(&value, rest);
+                    rest.with(Color::AnsiValue(240)).to_string()
+                }
+                None => String::default(),
+            };
+            self.prompt.clone() + &value + &format!("{}", self.cursor.view()) + &ghost
         } else {
-            self.prompt.clone() + &self.value
+            self.prompt.clone() + &value
         }
     }
 }
 
+/// The cursor character for the grapheme of `value` at `pos` under `echo_mode`.
+fn echo_char(echo_mode: EchoMode, value: &str, pos: usize) -> String {
+    match (echo_mode, value.graphemes(true).nth(pos)) {
+        (EchoMode::Normal, Some(c)) => c.to_string(),
+        (EchoMode::Password(mask), Some(_)) => mask.to_string(),
+        _ => " ".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TextInput;
+    use super::{EchoMode, TextInput, TextInputSubmitMsg};
     use crate::cursor::{Cursor, CursorMode};
     use crate::utils::{insert_char, remove_char};
-    use matcha::{KeyCode, KeyEvent, KeyModifiers, Model, Msg};
+    use matcha::{CursorShape, KeyCode, KeyEvent, KeyModifiers, Model, Msg};
     use proptest::prelude::*;
     use proptest::test_runner::Config as ProptestConfig;
     use unicode_segmentation::UnicodeSegmentation;
@@ -343,6 +664,22 @@ mod tests {
         input
     }
 
+    #[test]
+    fn focus_requests_cursor_shape() {
+        let (cursor, _) = Cursor::new().set_mode(CursorMode::Static);
+        let (_, cmd) = TextInput::new()
+            .set_cursor(cursor)
+            .cursor_shape(CursorShape::Bar)
+            .focus();
+
+        let Some(matcha::Cmd::Sync(matcha::SyncCmd(cmd))) = cmd else {
+            panic!("focus should request a cursor shape");
+        };
+        let msg = cmd();
+        let shape = msg.downcast_ref::<matcha::SetCursorShapeMsg>().map(|m| m.0);
+        assert_eq!(shape, Some(CursorShape::Bar));
+    }
+
     #[derive(Clone, Debug)]
     enum Op {
         Left,
@@ -380,6 +717,178 @@ mod tests {
         (value, pos)
     }
 
+    #[test]
+    fn combining_mark_merges_with_previous_grapheme() {
+        let input = focused_input(String::new(), 0);
+        let (input, _) = input.update(&key_msg(KeyCode::Char('e')));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('\u{301}')));
+
+        assert_eq!(
+            input.value.graphemes(true).collect::<Vec<_>>(),
+            vec!["e\u{301}"]
+        );
+        assert_eq!(input.pos, 1);
+    }
+
+    #[test]
+    fn arrow_keys_move_cursor_when_focused() {
+        let input = focused_input("abc".to_string(), 3);
+        let (input, _) = input.update(&key_msg(KeyCode::Left));
+        assert_eq!(input.pos, 2);
+        let (input, _) = input.update(&key_msg(KeyCode::Left));
+        assert_eq!(input.pos, 1);
+        let (input, _) = input.update(&key_msg(KeyCode::Right));
+        assert_eq!(input.pos, 2);
+    }
+
+    #[test]
+    fn password_mode_masks_value_but_keeps_it() {
+        let input = focused_input("pässwörd".to_string(), 0).echo_mode(EchoMode::Password('•'));
+        let (input, _) = input.update(&key_msg(KeyCode::Right));
+        let (input, _) = input.update(&key_msg(KeyCode::Right));
+        let view = matcha::remove_escape_sequences(&input.view().to_string());
+        assert_eq!(view, "> ••••••••");
+        assert!(!view.contains('s'));
+        assert_eq!(input.value(), "pässwörd");
+        assert_eq!(input.pos, 2);
+    }
+
+    #[test]
+    fn none_mode_renders_only_prompt_and_cursor() {
+        let input = focused_input("secret".to_string(), 3).echo_mode(EchoMode::None);
+        let (input, _) = input.update(&key_msg(KeyCode::Char('!')));
+        let view = matcha::remove_escape_sequences(&input.view().to_string());
+        assert_eq!(view, ">  ");
+        assert_eq!(input.value(), "sec!ret");
+    }
+
+    #[test]
+    fn rejected_input_leaves_value_and_cursor_alone() {
+        let input = focused_input("12".to_string(), 2)
+            .char_filter(|c| c.is_ascii_digit())
+            .validate(|value| value.len() <= 3 && !value.is_empty());
+        let (input, _) = input.update(&key_msg(KeyCode::Char('x')));
+        assert_eq!((input.value(), input.pos), ("12", 2));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('3')));
+        assert_eq!((input.value(), input.pos), ("123", 3));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('4')));
+        assert_eq!((input.value(), input.pos), ("123", 3));
+        assert!(input.is_valid());
+
+        // Deleting down to an empty value is rejected too.
+        let (input, _) = input.update(&key_msg(KeyCode::Backspace));
+        let (input, _) = input.update(&key_msg(KeyCode::Backspace));
+        let (input, _) = input.update(&key_msg(KeyCode::Backspace));
+        assert_eq!((input.value(), input.pos), ("1", 1));
+
+        assert!(!input.set_value("12345").is_valid());
+    }
+
+    #[test]
+    fn char_limit_stops_insertion() {
+        let input = focused_input("日本".to_string(), 2).char_limit(3);
+        assert_eq!(input.remaining(), Some(1));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('語')));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('!')));
+        assert_eq!((input.value(), input.pos), ("日本語", 3));
+        assert_eq!(input.remaining(), Some(0));
+        assert_eq!(TextInput::new().remaining(), None);
+    }
+
+    #[test]
+    fn narrow_input_scrolls_to_keep_cursor_visible() {
+        let view = |input: &TextInput| matcha::remove_escape_sequences(&input.view().to_string());
+        let input = focused_input("abcdefgh".to_string(), 8).width(4);
+        // The cursor at the end takes the last cell.
+        assert_eq!(view(&input), "> fgh ");
+
+        let mut input = input;
+        for _ in 0..6 {
+            (input, _) = input.update(&key_msg(KeyCode::Left));
+        }
+        assert_eq!(input.pos, 2);
+        assert_eq!(view(&input), "> cdef");
+
+        let (input, _) = input.update(&key_msg(KeyCode::Left));
+        assert_eq!(view(&input), "> bcde");
+
+        // Wide graphemes are measured in columns.
+        let input = focused_input("日本語".to_string(), 3).width(4);
+        assert_eq!(view(&input), "> 語 ");
+        let mut input = input;
+        for _ in 0..3 {
+            (input, _) = input.update(&key_msg(KeyCode::Left));
+        }
+        assert_eq!(view(&input), "> 日本");
+    }
+
+    #[test]
+    fn enter_submits_the_value() {
+        let input = focused_input("hello".to_string(), 2);
+        let (input, cmd) = input.update(&key_msg(KeyCode::Enter));
+        assert!(cmd.is_none(), "submitting is opt-in");
+
+        let (input, cmd) = input
+            .with_submit_on_enter(true)
+            .update(&key_msg(KeyCode::Enter));
+        let Some(matcha::Cmd::Sync(matcha::SyncCmd(cmd))) = cmd else {
+            panic!("enter should submit");
+        };
+        let msg = cmd();
+        let submitted = msg
+            .downcast_ref::<TextInputSubmitMsg>()
+            .map(|m| m.value.as_str());
+        assert_eq!(submitted, Some("hello"));
+
+        let (_, cmd) = input
+            .with_submit_on_enter(false)
+            .update(&key_msg(KeyCode::Enter));
+        assert!(cmd.is_none());
+    }
+
+    #[test]
+    fn suggestion_is_shown_dimmed_and_accepted() {
+        let view = |input: &TextInput| matcha::remove_escape_sequences(&input.view().to_string());
+        let input = focused_input(String::new(), 0)
+            .set_suggestions(vec!["apple".to_string(), "apricot".to_string()]);
+        assert_eq!(view(&input), ">  ");
+
+        let (input, _) = input.update(&key_msg(KeyCode::Char('a')));
+        let (input, _) = input.update(&key_msg(KeyCode::Char('p')));
+        assert_eq!(view(&input), "> apple");
+        assert_eq!(input.value(), "ap");
+        assert!(input
+            .view()
+            .to_string()
+            .contains(&matcha::Stylize::with("le", matcha::Color::AnsiValue(240)).to_string()));
+
+        let (input, _) = input.update(&key_msg(KeyCode::Char('r')));
+        assert_eq!(view(&input), "> apricot");
+        let (input, _) = input.update(&key_msg(KeyCode::Tab));
+        assert_eq!((input.value(), input.pos), ("apricot", 7));
+        assert_eq!(view(&input), "> apricot ");
+
+        // Right only accepts at the end; elsewhere it moves the cursor.
+        let (input, _) = input.update(&key_msg(KeyCode::Left));
+        let (input, _) = input.update(&key_msg(KeyCode::Right));
+        assert_eq!((input.value(), input.pos), ("apricot", 7));
+    }
+
+    #[test]
+    fn reset_clears_value_and_restores_placeholder() {
+        let view = |input: &TextInput| matcha::remove_escape_sequences(&input.view().to_string());
+        let input = focused_input(String::new(), 0).set_placeholder("name");
+        let (input, _) = input.update(&key_msg(KeyCode::Char('x')));
+        assert_eq!(view(&input), "> x ");
+
+        let input = input.reset();
+        assert_eq!((input.value(), input.pos), ("", 0));
+        assert_eq!(view(&input), "> name");
+
+        let input = focused_input("abc".to_string(), 3).reset();
+        assert_eq!(view(&input), ">  ");
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig {
             fork: false,
@@ -1,4 +1,5 @@
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub(crate) fn insert_char(value: String, at: usize, c: char) -> String {
@@ -50,6 +51,30 @@ pub(crate) fn split_at(value: String, at: usize) -> (String, String) {
     (head, tail)
 }
 
+/// Drops the first `width` columns of `value`, keeping ANSI escape sequences.
+///
+/// A wide grapheme that is cut in half leaves spaces behind so later columns keep their
+/// position.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub(crate) fn skip_width(value: &str, width: u16) -> String {
+    let mut skipped: u16 = 0;
+    let mut result = String::new();
+    let mut graphemes = value.graphemes(true);
+    while let Some(grapheme) = graphemes.next() {
+        if grapheme == "\x1b" {
+            matcha::push_escape_sequence(&mut graphemes, &mut result);
+        } else if skipped < width {
+            skipped = skipped.saturating_add(grapheme.width() as u16);
+            if skipped > width {
+                result.push_str(&" ".repeat((skipped - width) as usize));
+            }
+        } else {
+            result.push_str(grapheme);
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +84,28 @@ mod tests {
         value.graphemes(true).count()
     }
 
+    #[test]
+    fn skip_width_keeps_styles_and_splits_wide_graphemes() {
+        assert_eq!(skip_width("\x1b[31mabc\x1b[0m", 2), "\x1b[31mc\x1b[0m");
+        assert_eq!(skip_width("あい", 1), " い");
+        assert_eq!(skip_width("ab", 5), "");
+    }
+
+    #[test]
+    fn skip_width_keeps_hyperlinks_intact() {
+        let link = "\x1b]8;;https://example.com\x1b\\abc\x1b]8;;\x1b\\";
+        assert_eq!(
+            skip_width(link, 1),
+            "\x1b]8;;https://example.com\x1b\\bc\x1b]8;;\x1b\\"
+        );
+
+        let link = "\x1b]8;;https://example.com\x07abc\x1b]8;;\x07";
+        assert_eq!(
+            skip_width(link, 2),
+            "\x1b]8;;https://example.com\x07c\x1b]8;;\x07"
+        );
+    }
+
     proptest! {
         #[test]
         fn insert_then_remove_restores_original(
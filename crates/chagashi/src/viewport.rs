@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use matcha::*;
+use unicode_width::UnicodeWidthStr;
 
 /// KeyMap defines the keybindings for the viewport.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,11 +14,29 @@ pub enum ViewportKeys {
     Down,
     /// Up one line.
     Up,
+    /// Left one column.
+    Left,
+    /// Right one column.
+    Right,
+    /// Down half a page.
+    HalfPageDown,
+    /// Up half a page.
+    HalfPageUp,
+    /// To the top.
+    Top,
+    /// To the bottom.
+    Bottom,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Default keybinding set for [`Viewport`].
-pub struct Keybindings(matcha::KeyBindings<ViewportKeys>);
+///
+/// Start from [`Keybindings::default`] and use the [`matcha::KeyBindings`] helpers to
+/// rebind individual keys, then pass the result to [`Viewport::with_keybindings`].
+///
+/// Bindings can also come from [`InitInput::extensions`]: when it holds a
+/// `KeyBindings<ViewportKeys>`, [`Viewport`] uses it from [`Model::init`] on.
+pub struct Keybindings(pub matcha::KeyBindings<ViewportKeys>);
 
 /// Emit a message that indicates an item/line has been selected.
 ///
@@ -68,6 +87,12 @@ impl Default for Keybindings {
             (key!(up), ViewportKeys::Up),
             (key!(ctrl - v), ViewportKeys::PageDown),
             (key!(alt - v), ViewportKeys::PageUp),
+            (key!(left), ViewportKeys::Left),
+            (key!(right), ViewportKeys::Right),
+            (key!(ctrl - d), ViewportKeys::HalfPageDown),
+            (key!(ctrl - u), ViewportKeys::HalfPageUp),
+            (key!(g), ViewportKeys::Top),
+            (key!(shift - g), ViewportKeys::Bottom),
         ]
         .into_iter()
         .collect();
@@ -77,20 +102,30 @@ impl Default for Keybindings {
 
 /// the matcha model for this viewport element.
 ///
-/// `Viewport` renders a child model and provides vertical scrolling. It can optionally
-/// run in selection mode to highlight a line and emit selection messages.
+/// `Viewport` renders a child model and provides vertical scrolling. Without wrapping,
+/// lines wider than the viewport can also be scrolled horizontally; a `‹` or `›` in the
+/// first or last column marks content hidden on that side. It can optionally run in
+/// selection mode to highlight a line and emit selection messages.
 pub struct Viewport<M> {
     width: u16,
     height: u16,
     key_bindings: Keybindings,
     /// offset_y is the vertical scroll position.
     offset_y: u16,
+    /// offset_x is the horizontal scroll position in columns.
+    offset_x: u16,
     wrap: bool,
+    word_wrap: bool,
     // selection
     selection: bool,
     selection_y: u16,
     selection_fg: Color,
     selection_bg: Color,
+    scrollbar: bool,
+    mouse_wheel_delta: u16,
+    // search
+    search_query: String,
+    search_match: Option<u16>,
     child: M,
 }
 
@@ -99,21 +134,32 @@ pub struct Viewport<M> {
 pub struct ViewportOption {
     /// enable wrap mode.
     pub wrap: bool,
+    /// break wrapped lines at word boundaries instead of at the exact width.
+    /// Only takes effect when `wrap` is enabled.
+    pub word_wrap: bool,
     /// enable selection mode.
     pub selection: bool,
-    /// selection foreground color.
+    /// selection foreground color. Defaults to [`readable_fg`] of the default background.
     pub selection_fg: Color,
     /// selection background color.
     pub selection_bg: Color,
+    /// render a scrollbar in the rightmost column. The column is reserved while
+    /// enabled, but left blank when the content fits the height.
+    pub scrollbar: bool,
+    /// lines scrolled per mouse wheel notch; 0 ignores the wheel.
+    pub mouse_wheel_delta: u16,
 }
 
 impl Default for ViewportOption {
     fn default() -> Self {
         Self {
             wrap: false,
+            word_wrap: false,
             selection: false,
-            selection_fg: Color::Black,
+            selection_fg: readable_fg(Color::Yellow),
             selection_bg: Color::Yellow,
+            scrollbar: false,
+            mouse_wheel_delta: 3,
         }
     }
 }
@@ -127,12 +173,18 @@ impl<M: Model> Viewport<M> {
             height: size.1,
             key_bindings: Keybindings::default(),
             offset_y: 0,
+            offset_x: 0,
             wrap: opt.wrap,
+            word_wrap: opt.word_wrap,
             // selection config
             selection_y: 0,
             selection: opt.selection,
             selection_fg: opt.selection_fg,
             selection_bg: opt.selection_bg,
+            scrollbar: opt.scrollbar,
+            mouse_wheel_delta: opt.mouse_wheel_delta,
+            search_query: String::new(),
+            search_match: None,
             child,
         }
     }
@@ -147,12 +199,108 @@ impl<M: Model> Viewport<M> {
         }
     }
 
-    /// at_bottom returns whether or not the viewport is at the very top position.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
-    fn at_bottom(&self) -> bool {
+    /// Replace the keybindings.
+    pub fn with_keybindings(self, key_bindings: Keybindings) -> Self {
+        Self {
+            key_bindings,
+            ..self
+        }
+    }
+
+    /// at_top returns whether or not the viewport is at the very top position.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn at_top(&self) -> bool {
+        self.offset_y == 0
+    }
+
+    /// at_bottom returns whether or not the viewport is at or past the bottom position.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn at_bottom(&self) -> bool {
         self.offset_y >= self.max_y_offset()
     }
 
+    /// scroll_percent returns how far the viewport is scrolled, from 0.0 at the top to
+    /// 1.0 at the bottom. Content that fits the height counts as fully scrolled.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn scroll_percent(&self) -> f32 {
+        let max = self.max_y_offset();
+        if max == 0 {
+            return 1.0;
+        }
+        (self.offset_y.min(max) as f32 / max as f32).clamp(0.0, 1.0)
+    }
+
+    /// total_line_count returns the number of content lines, after wrapping.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn total_line_count(&self) -> u16 {
+        self.content_len()
+    }
+
+    /// visible_line_count returns the number of content lines currently shown.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn visible_line_count(&self) -> u16 {
+        self.content_len()
+            .saturating_sub(self.offset_y)
+            .min(self.height)
+    }
+
+    /// Scroll so that content line `line` (0-based, before wrapping) is at the top,
+    /// clamped to the bottom position.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn goto_line(self, line: u16) -> Self {
+        let offset_y = self.display_line_of(line as usize).min(self.max_y_offset());
+        Self { offset_y, ..self }
+    }
+
+    /// Find the next content line containing `query`, scroll it into view and
+    /// highlight it with the selection colors.
+    ///
+    /// Searching again for the same query moves on to the following match, wrapping
+    /// around at the end. A new query starts at the top visible line. Escape sequences
+    /// are ignored when matching; an empty query clears the highlight.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn search(self, query: &str) -> Self {
+        if query.is_empty() {
+            return Self {
+                search_query: String::new(),
+                search_match: None,
+                ..self
+            };
+        }
+        let lines = self.child_lines();
+        let start = match self.search_match {
+            Some(index) if self.search_query == query => index as usize + 1,
+            _ => self.child_line_at(self.offset_y),
+        };
+        let found = (0..lines.len())
+            .map(|i| (start + i) % lines.len())
+            .find(|&i| matcha::remove_escape_sequences(&lines[i]).contains(query));
+        let offset_y = match found {
+            Some(index) => {
+                let y = self.display_line_of(index);
+                if y < self.offset_y || y >= self.offset_y.saturating_add(self.height) {
+                    y.min(self.max_y_offset())
+                } else {
+                    self.offset_y
+                }
+            }
+            None => self.offset_y,
+        };
+        Self {
+            offset_y,
+            search_query: query.to_string(),
+            search_match: found.map(|index| index as u16),
+            ..self
+        }
+    }
+
+    /// The content line (0-based, before wrapping) highlighted by the last
+    /// [`Viewport::search`], if it found one.
+    pub fn search_match(&self) -> Option<u16> {
+        self.search_match
+    }
+
     /// sets the viewport to the top position.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     ///
@@ -259,6 +407,107 @@ impl<M: Model> Viewport<M> {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Scroll up by half a page.
+    pub fn half_page_up(self) -> Self {
+        let delta = (self.height / 2).max(1);
+        Self {
+            offset_y: self.offset_y.saturating_sub(delta),
+            selection_y: self.selection_y.saturating_sub(delta),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Scroll down by half a page, stopping at the bottom.
+    pub fn half_page_down(self) -> Self {
+        let delta = (self.height / 2).max(1);
+        Self {
+            offset_y: self.offset_y.saturating_add(delta).min(self.max_y_offset()),
+            selection_y: self
+                .selection_y
+                .saturating_add(delta)
+                .min(self.content_len().saturating_sub(1)),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Scroll left by one column.
+    pub fn move_left(self) -> Self {
+        Self {
+            offset_x: self.offset_x.saturating_sub(1),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    /// Scroll right by one column, stopping once the widest line is fully shown.
+    pub fn move_right(self) -> Self {
+        Self {
+            offset_x: self.offset_x.saturating_add(1).min(self.max_x_offset()),
+            ..self
+        }
+    }
+
+    /// max_x_offset returns the maximum possible value of the x-offset based on the
+    /// widest content line and the viewport's width. It is always 0 in wrap mode.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn max_x_offset(&self) -> u16 {
+        if self.wrap {
+            return 0;
+        }
+        let widest = self.line_widths().into_iter().max().unwrap_or_default();
+        widest.saturating_sub(self.content_width())
+    }
+
+    /// Returns the child view split into lines, before wrapping.
+    fn child_lines(&self) -> Vec<String> {
+        let child = format!("{}", self.child.view());
+        child.split('\n').map(String::from).collect()
+    }
+
+    /// Number of rendered lines a child line takes up.
+    fn segment_count(&self, line: &str) -> u16 {
+        if !self.wrap {
+            1
+        } else if self.word_wrap {
+            matcha::wrap_words(line, self.content_width()).len() as u16
+        } else {
+            matcha::wrap(line, self.content_width()).len() as u16
+        }
+    }
+
+    /// Returns the rendered line where child line `index` starts.
+    fn display_line_of(&self, index: usize) -> u16 {
+        self.child_lines()
+            .iter()
+            .take(index)
+            .map(|line| self.segment_count(line))
+            .fold(0, u16::saturating_add)
+    }
+
+    /// Returns the child line shown on rendered line `y`.
+    fn child_line_at(&self, y: u16) -> usize {
+        let mut start = 0u16;
+        for (index, line) in self.child_lines().iter().enumerate() {
+            start = start.saturating_add(self.segment_count(line));
+            if start > y {
+                return index;
+            }
+        }
+        0
+    }
+
+    /// Returns the display width of each child line, ignoring escape sequences.
+    fn line_widths(&self) -> Vec<u16> {
+        let child = format!("{}", self.child.view());
+        child
+            .split('\n')
+            .map(|line| matcha::remove_escape_sequences(line).width() as u16)
+            .collect()
+    }
+
     /// Renders the child view into padded lines, applying wrapping and selection styling.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn lines(&self) -> Vec<String> {
@@ -270,10 +519,12 @@ impl<M: Model> Viewport<M> {
             .collect()
     }
 
-    /// Returns true if the 0-based index corresponds to the currently selected line.
+    /// Returns true if the 0-based index corresponds to the currently selected line or
+    /// the current search match.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn is_selected_line(&self, index: usize) -> bool {
-        self.selection && index == self.selection_y as usize
+        (self.selection && index == self.selection_y as usize)
+            || self.search_match == Some(index as u16)
     }
 
     /// Splits a line into renderable segments based on the wrap configuration.
@@ -289,7 +540,12 @@ impl<M: Model> Viewport<M> {
     /// Wraps a line at the viewport width and renders each resulting segment.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn render_wrapped_segments(&self, line: &str, is_selected: bool) -> Vec<String> {
-        matcha::wrap(line, self.width)
+        let segments = if self.word_wrap {
+            matcha::wrap_words(line, self.content_width())
+        } else {
+            matcha::wrap(line, self.content_width())
+        };
+        segments
             .into_iter()
             .map(|segment| self.render_wrapped_segment(&segment, is_selected))
             .collect()
@@ -309,7 +565,7 @@ impl<M: Model> Viewport<M> {
     /// Pads and styles a single unwrapped segment.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn render_single_segment(&self, segment: &str, is_selected: bool) -> String {
-        let padded = self.pad_to_width(segment);
+        let padded = self.pad_to_width(&crate::utils::skip_width(segment, self.offset_x));
         if is_selected {
             self.highlight_selection(padded)
         } else {
@@ -317,9 +573,47 @@ impl<M: Model> Viewport<M> {
         }
     }
 
-    /// Right-pads the segment with spaces to match the viewport width.
+    /// Right-pads the segment with spaces to match the content width.
     fn pad_to_width(&self, segment: &str) -> String {
-        matcha::fill_by_space(segment.to_string(), self.width)
+        matcha::fill_by_space(segment.to_string(), self.content_width())
+    }
+
+    /// Width available for content, excluding the scrollbar column.
+    fn content_width(&self) -> u16 {
+        if self.scrollbar {
+            self.width.saturating_sub(1)
+        } else {
+            self.width
+        }
+    }
+
+    /// Returns the scrollbar column from top to bottom: a `│` track with a `█` thumb, or
+    /// blanks when there is nothing to scroll.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn scrollbar_column(&self) -> Vec<&'static str> {
+        let height = self.height as usize;
+        let content_len = (self.content_len() as usize).max(1);
+        if content_len <= height {
+            return vec![" "; height];
+        }
+        let thumb_len = (height * height / content_len).clamp(1, height.max(1));
+        let max_offset = self.max_y_offset() as usize;
+        let thumb_top = if max_offset == 0 {
+            0
+        } else {
+            let track = height.saturating_sub(thumb_len);
+            (std::cmp::min(self.offset_y as usize, max_offset) * track + max_offset / 2)
+                / max_offset
+        };
+        (0..height)
+            .map(|i| {
+                if i >= thumb_top && i < thumb_top + thumb_len {
+                    "█"
+                } else {
+                    "│"
+                }
+            })
+            .collect()
     }
 
     /// Applies the configured selection colors to the given text.
@@ -369,8 +663,37 @@ impl<M: Model> Viewport<M> {
         }
     }
 
+    /// Returns the plain text currently shown, without styling, padding or scrollbar.
+    ///
+    /// Unlike [`Model::view`], ANSI escape sequences are stripped and trailing spaces are
+    /// trimmed from each visible line. Lines are joined with `\n`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn visible_text(&self) -> String {
+        self.visible_content_lines()
+            .iter()
+            .map(|line| matcha::remove_escape_sequences(line).trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn visible_lines(&self) -> Vec<String> {
+        let lines = self.visible_content_lines();
+        if self.scrollbar {
+            let width = self.content_width();
+            lines
+                .into_iter()
+                .zip(self.scrollbar_column())
+                .map(|(line, bar)| format!("{}{}", matcha::fill_by_space(line, width), bar))
+                .collect()
+        } else {
+            lines
+        }
+    }
+
+    /// Returns the visible lines clamped to the content width, filled up to the height.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn visible_content_lines(&self) -> Vec<String> {
         let content_len = self.content_len();
         let top = self.offset_y.min(content_len) as usize;
         let over = content_len.saturating_sub(self.offset_y) > self.height;
@@ -381,8 +704,11 @@ impl<M: Model> Viewport<M> {
         };
         let mut lines: Vec<String> = self.lines()[top..bottom]
             .iter()
-            .map(|line| matcha::clamp_by(line, self.width).replace('\r', ""))
+            .map(|line| matcha::clamp_by(line, self.content_width()).replace('\r', ""))
             .collect();
+        if !self.wrap {
+            self.mark_hidden_columns(&mut lines[..], &self.line_widths()[top..bottom]);
+        }
 
         // if not overed, fill with \n to keep height.
         if !over {
@@ -396,9 +722,32 @@ impl<M: Model> Viewport<M> {
     }
 }
 
+impl<M: Model> Viewport<M> {
+    /// Puts `‹` in the first column of lines scrolled past and `›` in the last column of
+    /// lines that continue beyond the right edge. `widths` are the full line widths.
+    fn mark_hidden_columns(&self, lines: &mut [String], widths: &[u16]) {
+        let width = self.content_width();
+        if width < 2 {
+            return;
+        }
+        for (line, &full) in lines.iter_mut().zip(widths) {
+            if full > self.offset_x.saturating_add(width) {
+                *line = format!("{}›", matcha::clamp_by(line, width - 1));
+            }
+            if self.offset_x > 0 && full > 0 {
+                *line = format!("‹{}", crate::utils::skip_width(line, 1));
+            }
+        }
+    }
+}
+
 impl<M: Model> Model for Viewport<M> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+        let key_bindings = match input.extensions.get::<KeyBindings<ViewportKeys>>() {
+            Some(bindings) => Keybindings(bindings.clone()),
+            None => self.key_bindings,
+        };
         let (child, cmd) = self.child.init(input);
         let mut cmds = vec![];
         if let Some(cmd) = cmd {
@@ -409,15 +758,12 @@ impl<M: Model> Model for Viewport<M> {
             let m = Box::new(ViewportOnSelectMsg::new(self.selection_y));
             cmds.push(Cmd::sync(Box::new(move || m)));
         }
-        let cmd = if cmds.is_empty() {
-            None
-        } else {
-            Some(batch(cmds))
-        };
+        let cmd = matcha::cmds(cmds);
         (
             Self {
                 width: input.size.0,
                 height: input.size.1,
+                key_bindings,
                 child,
                 ..self
             },
@@ -437,38 +783,43 @@ impl<M: Model> Model for Viewport<M> {
             child: new_child,
             ..self
         };
-        let (new_self, cmd): (Self, Option<Cmd>) =
-            if let Some(event) = msg.downcast_ref::<KeyEvent>() {
-                let key = new_self.key_bindings.0.get(matcha::Key::from(event));
-                let new_self = match key {
-                    Some(ViewportKeys::Down) => new_self.move_down(),
-                    Some(ViewportKeys::Up) => new_self.move_up(),
-                    Some(ViewportKeys::PageDown) => new_self.page_down(),
-                    Some(ViewportKeys::PageUp) => new_self.page_up(),
-                    _ => new_self,
-                };
+        let new_self = if let Some(event) = msg.downcast_ref::<KeyEvent>() {
+            let key = new_self.key_bindings.0.get(matcha::Key::from(event));
+            match key {
+                Some(ViewportKeys::Down) => new_self.move_down(),
+                Some(ViewportKeys::Up) => new_self.move_up(),
+                Some(ViewportKeys::PageDown) => new_self.page_down(),
+                Some(ViewportKeys::PageUp) => new_self.page_up(),
+                Some(ViewportKeys::Left) => new_self.move_left(),
+                Some(ViewportKeys::Right) => new_self.move_right(),
+                Some(ViewportKeys::HalfPageDown) => new_self.half_page_down(),
+                Some(ViewportKeys::HalfPageUp) => new_self.half_page_up(),
+                Some(ViewportKeys::Top) => new_self.move_to_top(),
+                Some(ViewportKeys::Bottom) => new_self.move_to_bottom(),
+                _ => new_self,
+            }
+        } else if let Some(event) = msg.downcast_ref::<MouseEvent>() {
+            // The viewport doesn't know where it is drawn, so every wheel event scrolls it.
+            let notch = 0..new_self.mouse_wheel_delta;
+            match event.kind {
+                MouseEventKind::ScrollDown => notch.fold(new_self, |v, _| v.move_down()),
+                MouseEventKind::ScrollUp => notch.fold(new_self, |v, _| v.move_up()),
+                _ => new_self,
+            }
+        } else {
+            new_self
+        };
 
-                #[cfg(feature = "tracing")]
-                tracing::trace!("selection_y = {}", old_selection_y);
+        #[cfg(feature = "tracing")]
+        tracing::trace!("selection_y = {}", old_selection_y);
 
-                if new_self.selection && old_selection_y != new_self.selection_y {
-                    let index = new_self.selection_y;
-                    let cmd = Cmd::sync(Box::new(move || Box::new(ViewportOnSelectMsg { index })));
-                    (new_self, Some(cmd))
-                } else {
-                    (new_self, None)
-                }
-            } else {
-                (new_self, None)
-            };
-        if let Some(c) = cmd {
-            commands.push(c);
+        if new_self.selection && old_selection_y != new_self.selection_y {
+            let index = new_self.selection_y;
+            commands.push(Cmd::sync(Box::new(move || {
+                Box::new(ViewportOnSelectMsg { index })
+            })));
         }
-        let cmd = if commands.is_empty() {
-            None
-        } else {
-            Some(batch(commands))
-        };
+        let cmd = matcha::cmds(commands);
         (new_self, cmd)
     }
 
@@ -484,7 +835,6 @@ mod tests {
     use super::*;
     use matcha::{style, Color};
     use proptest::prelude::*;
-    use unicode_width::UnicodeWidthStr;
 
     #[derive(Clone)]
     struct StaticModel(String);
@@ -518,6 +868,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lines_word_wrap_when_enabled() {
+        let opt = ViewportOption {
+            wrap: true,
+            word_wrap: true,
+            ..ViewportOption::default()
+        };
+        let viewport = build_viewport(opt, "ab cdef", (4, 2));
+        assert_eq!(
+            viewport.lines(),
+            vec!["ab  ".to_string(), "cdef".to_string()]
+        );
+    }
+
     #[test]
     fn lines_highlight_selected_line() {
         let selection_fg = Color::White;
@@ -568,6 +932,152 @@ mod tests {
         assert!(cmd.is_none());
     }
 
+    #[test]
+    fn with_keybindings_rebinds_down() {
+        let mut key_bindings = Keybindings::default();
+        key_bindings.0.remove(&Key(key!(down)));
+        key_bindings.0.insert(Key(key!(j)), ViewportKeys::Down);
+        let viewport = build_viewport(ViewportOption::default(), "a\nb\nc", (3, 1))
+            .with_keybindings(key_bindings);
+
+        let down: Msg = Box::new(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()));
+        let (viewport, _) = viewport.update(&down);
+        assert_eq!(viewport.offset_y, 0);
+
+        let j: Msg = Box::new(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()));
+        let (viewport, _) = viewport.update(&j);
+        assert_eq!(viewport.offset_y, 1);
+    }
+
+    #[test]
+    fn mouse_wheel_scrolls_by_delta() {
+        let opt = ViewportOption {
+            mouse_wheel_delta: 2,
+            ..ViewportOption::default()
+        };
+        let viewport = build_viewport(opt, "a\nb\nc\nd\ne", (3, 2));
+        let wheel = |kind| -> Msg {
+            Box::new(MouseEvent {
+                kind,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        let (viewport, _) = viewport.update(&wheel(MouseEventKind::ScrollDown));
+        assert_eq!(viewport.offset_y, 2);
+        let (viewport, _) = viewport.update(&wheel(MouseEventKind::ScrollDown));
+        assert_eq!(viewport.offset_y, 3);
+        let (viewport, _) = viewport.update(&wheel(MouseEventKind::ScrollUp));
+        assert_eq!(viewport.offset_y, 1);
+    }
+
+    #[test]
+    fn scroll_position_accessors() {
+        let viewport = build_viewport(ViewportOption::default(), "a\nb\nc\nd\ne", (3, 2));
+        assert!(viewport.at_top() && !viewport.at_bottom());
+        assert_eq!(viewport.scroll_percent(), 0.0);
+        assert_eq!(viewport.total_line_count(), 5);
+        assert_eq!(viewport.visible_line_count(), 2);
+
+        let viewport = viewport.move_down().move_down().move_down();
+        assert!(!viewport.at_top() && viewport.at_bottom());
+        assert_eq!(viewport.scroll_percent(), 1.0);
+
+        let viewport = viewport.move_up();
+        assert_eq!(viewport.scroll_percent(), 2.0 / 3.0);
+
+        let viewport = build_viewport(ViewportOption::default(), "a", (3, 2));
+        assert_eq!(viewport.scroll_percent(), 1.0);
+        assert_eq!(viewport.visible_line_count(), 1);
+    }
+
+    #[test]
+    fn goto_line_puts_line_at_top_and_clamps() {
+        let content = (0..10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let viewport = build_viewport(ViewportOption::default(), &content, (3, 3));
+        let viewport = viewport.goto_line(4);
+        assert_eq!(viewport.offset_y, 4);
+        assert_eq!(viewport.goto_line(100).offset_y, 7);
+
+        let opt = ViewportOption {
+            wrap: true,
+            ..ViewportOption::default()
+        };
+        // "abcdef" wraps into two lines.
+        let viewport = build_viewport(opt, "abcdef\nx\ny\nz", (3, 2)).goto_line(2);
+        assert_eq!(viewport.visible_text(), "y\nz");
+    }
+
+    #[test]
+    fn search_cycles_through_matches_and_highlights() {
+        let banana = style("banana").with(Color::Red).to_string();
+        let content = format!("apple\n{banana}\ncherry\ndate\nbanana split");
+        let viewport = build_viewport(ViewportOption::default(), &content, (12, 2));
+
+        let viewport = viewport.search("banana");
+        assert_eq!(viewport.search_match(), Some(1));
+        assert_eq!(viewport.offset_y, 0);
+        let highlighted = viewport.highlight_selection(viewport.pad_to_width(&banana));
+        assert_eq!(viewport.lines()[1], highlighted);
+
+        let viewport = viewport.search("banana");
+        assert_eq!(viewport.search_match(), Some(4));
+        assert_eq!(viewport.offset_y, 3);
+
+        // Wraps around to the first match.
+        let viewport = viewport.search("banana");
+        assert_eq!(viewport.search_match(), Some(1));
+        assert_eq!(viewport.offset_y, 1);
+
+        let viewport = viewport.search("nothing");
+        assert_eq!(viewport.search_match(), None);
+        assert_eq!(viewport.search("").search_match(), None);
+    }
+
+    #[test]
+    fn half_page_and_top_bottom_keys() {
+        let content = (0..10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let viewport = build_viewport(ViewportOption::default(), &content, (3, 4));
+        let press = |viewport: Viewport<StaticModel>, code, modifiers| {
+            let msg: Msg = Box::new(KeyEvent::new(code, modifiers));
+            viewport.update(&msg).0
+        };
+
+        let viewport = press(viewport, KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert_eq!(viewport.offset_y, 2);
+        let viewport = press(viewport, KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(viewport.offset_y, 6);
+        let viewport = press(viewport, KeyCode::Char('d'), KeyModifiers::CONTROL);
+        assert_eq!(viewport.offset_y, 6);
+        let viewport = press(viewport, KeyCode::Char('u'), KeyModifiers::CONTROL);
+        assert_eq!(viewport.offset_y, 4);
+        let viewport = press(viewport, KeyCode::Char('g'), KeyModifiers::NONE);
+        assert_eq!(viewport.offset_y, 0);
+    }
+
+    #[test]
+    fn init_uses_keybindings_from_extensions() {
+        let mut bindings = Keybindings::default().0;
+        bindings.insert(Key(key!(j)), ViewportKeys::Down);
+        let mut extensions = Extensions::new();
+        extensions.insert(bindings);
+        let input = InitInput::new((3, 1)).with_extensions(extensions);
+        let (viewport, _) =
+            build_viewport(ViewportOption::default(), "a\nb\nc", (3, 1)).init(&input);
+
+        let j: Msg = Box::new(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()));
+        let (viewport, _) = viewport.update(&j);
+        assert_eq!(viewport.offset_y, 1);
+    }
+
     #[test]
     fn visible_lines_does_not_panic_when_offset_exceeds_content() {
         let mut viewport = build_viewport(ViewportOption::default(), "a\nb", (3, 2));
@@ -575,6 +1085,75 @@ mod tests {
         assert_eq!(viewport.visible_lines(), vec![String::new(), String::new()]);
     }
 
+    #[test]
+    fn visible_text_strips_styling_and_padding() {
+        let opt = ViewportOption {
+            selection: true,
+            scrollbar: true,
+            ..ViewportOption::default()
+        };
+        let content = format!("first\n{}\nthird", style("second").with(Color::Red));
+        let viewport = build_viewport(opt, &content, (10, 4)).move_down();
+
+        assert_eq!(viewport.visible_text(), "first\nsecond\nthird\n");
+    }
+
+    #[test]
+    fn scrollbar_thumb_follows_offset() {
+        let opt = ViewportOption {
+            scrollbar: true,
+            ..ViewportOption::default()
+        };
+        let content = (0..20)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let viewport = build_viewport(opt, &content, (10, 5));
+        let bar = |v: &Viewport<StaticModel>| -> Vec<String> {
+            v.visible_lines()
+                .iter()
+                .map(|line| line.chars().last().unwrap().to_string())
+                .collect()
+        };
+
+        assert_eq!(bar(&viewport), vec!["█", "│", "│", "│", "│"]);
+        assert_eq!(viewport.visible_lines()[0], "line 0   █");
+
+        let viewport = viewport.move_to_bottom();
+        assert_eq!(viewport.offset_y, viewport.max_y_offset());
+        assert_eq!(bar(&viewport), vec!["│", "│", "│", "│", "█"]);
+    }
+
+    #[test]
+    fn scrollbar_is_blank_when_content_fits() {
+        let opt = ViewportOption {
+            scrollbar: true,
+            ..ViewportOption::default()
+        };
+        let viewport = build_viewport(
+            opt,
+            "a
+b",
+            (4, 3),
+        );
+        assert_eq!(viewport.visible_lines(), vec!["a   ", "b   ", "    "]);
+    }
+
+    #[test]
+    fn move_right_stops_at_max_x_offset_and_marks_hidden_columns() {
+        let viewport = build_viewport(ViewportOption::default(), "0123456789\nab", (4, 2));
+        assert_eq!(viewport.max_x_offset(), 6);
+        assert_eq!(viewport.visible_lines(), vec!["012›", "ab  "]);
+
+        // `ab` is scrolled past entirely, so it is marked as hidden on the left.
+        let viewport = (0..10).fold(viewport, |v, _| v.move_right());
+        assert_eq!(viewport.offset_x, viewport.max_x_offset());
+        assert_eq!(viewport.visible_lines(), vec!["‹789", "‹   "]);
+
+        let viewport = viewport.move_left().move_left().move_left();
+        assert_eq!(viewport.visible_lines(), vec!["‹45›", "‹   "]);
+    }
+
     fn join_lines(lines: &[String]) -> String {
         lines.join("\n")
     }
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use chagashi::textarea::Textarea;
-use matcha::{batch, quit, Cmd, Extensions, InitInput, KeyCode, KeyEvent, Model, Msg, Program};
+use matcha::{cmds, quit, Cmd, Extensions, InitInput, KeyCode, KeyEvent, Model, Msg, Program};
 
 struct App {
     textarea: Textarea,
@@ -9,14 +9,16 @@ struct App {
 
 impl Model for App {
     fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
-        let (borderize, init_cmd) = self.textarea.init(&InitInput {
-            size: (60, std::cmp::min(8, input.size.1)),
-        });
+        let (borderize, init_cmd) = self.textarea.init(
+            &input
+                .clone()
+                .with_size((60, std::cmp::min(8, input.size.1))),
+        );
         let (textarea, focus_cmd) = borderize.focus();
-        let mut cmds: matcha::BatchMsg = vec![];
-        init_cmd.into_iter().for_each(|c| cmds.push(c));
-        focus_cmd.into_iter().for_each(|c| cmds.push(c));
-        (Self { textarea }, Some(batch(cmds)))
+        (
+            Self { textarea },
+            cmds(init_cmd.into_iter().chain(focus_cmd)),
+        )
     }
 
     fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
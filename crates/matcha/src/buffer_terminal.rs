@@ -0,0 +1,249 @@
+use std::sync::{Arc, Mutex};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{remove_escape_sequences, Termable};
+
+/// An in-memory [`Termable`] that keeps a grid of the cells printed to it.
+///
+/// This makes it possible to run a whole [`crate::Program`] without a TTY and assert on
+/// what ended up on the screen. Clones share the same screen, so keep one clone around
+/// to read the result after handing the other to the program.
+///
+/// Styling escape sequences are dropped and control characters other than `\r` and
+/// `\n` are ignored. Text wraps at the right edge and the screen scrolls at the bottom.
+///
+/// # Examples
+///
+/// ```
+/// use matcha::{BufferTerminal, Termable};
+///
+/// let term = BufferTerminal::new(10, 2);
+/// term.print("hello\r\nworld").unwrap();
+/// assert_eq!(term.contents(), "hello\nworld");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BufferTerminal {
+    screen: Arc<Mutex<Screen>>,
+}
+
+#[derive(Debug)]
+struct Screen {
+    width: u16,
+    height: u16,
+    cells: Vec<Vec<String>>,
+    x: u16,
+    y: u16,
+    /// The main screen while the alternate screen is active.
+    saved: Option<Vec<Vec<String>>>,
+}
+
+impl Screen {
+    fn blank(width: u16, height: u16) -> Vec<Vec<String>> {
+        vec![vec![" ".to_string(); width as usize]; height as usize]
+    }
+
+    fn clear_row(&mut self, y: u16) {
+        if let Some(row) = self.cells.get_mut(y as usize) {
+            row.iter_mut().for_each(|cell| *cell = " ".to_string());
+        }
+    }
+
+    fn new_line(&mut self) {
+        if self.y + 1 < self.height {
+            self.y += 1;
+        } else if self.height > 0 {
+            self.cells.remove(0);
+            self.cells.push(vec![" ".to_string(); self.width as usize]);
+        }
+    }
+
+    fn put(&mut self, grapheme: &str) {
+        let width = grapheme.width() as u16;
+        if width == 0 || width > self.width {
+            return;
+        }
+        if self.x + width > self.width {
+            self.x = 0;
+            self.new_line();
+        }
+        if let Some(row) = self.cells.get_mut(self.y as usize) {
+            let x = self.x as usize;
+            row[x] = grapheme.to_string();
+            // The following cells are covered by a wide grapheme.
+            for cell in row.iter_mut().skip(x + 1).take(width as usize - 1) {
+                cell.clear();
+            }
+        }
+        self.x += width;
+    }
+
+    fn print(&mut self, v: &str) {
+        for g in remove_escape_sequences(v).graphemes(true) {
+            match g {
+                "\r\n" => {
+                    self.x = 0;
+                    self.new_line();
+                }
+                "\r" => self.x = 0,
+                "\n" => self.new_line(),
+                g if g.chars().all(char::is_control) => {}
+                g => self.put(g),
+            }
+        }
+    }
+}
+
+impl BufferTerminal {
+    /// Create a blank terminal of `width` x `height` cells.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            screen: Arc::new(Mutex::new(Screen {
+                width,
+                height,
+                cells: Screen::blank(width, height),
+                x: 0,
+                y: 0,
+                saved: None,
+            })),
+        }
+    }
+
+    /// Return the text on screen, one row per line with trailing spaces trimmed.
+    pub fn contents(&self) -> String {
+        let screen = self.screen.lock().unwrap();
+        screen
+            .cells
+            .iter()
+            .map(|row| row.concat().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Termable for BufferTerminal {
+    fn size(&self) -> Result<(u16, u16), std::io::Error> {
+        let screen = self.screen.lock().unwrap();
+        Ok((screen.width, screen.height))
+    }
+
+    fn hide_cursor(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn show_cursor(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn enable_raw_mode(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn print(&self, v: &str) -> Result<(), std::io::Error> {
+        self.screen.lock().unwrap().print(v);
+        Ok(())
+    }
+
+    fn enter_alt_screen(&self) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        if screen.saved.is_none() {
+            let blank = Screen::blank(screen.width, screen.height);
+            screen.saved = Some(std::mem::replace(&mut screen.cells, blank));
+        }
+        Ok(())
+    }
+
+    fn leave_alt_screen(&self) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        if let Some(cells) = screen.saved.take() {
+            screen.cells = cells;
+        }
+        Ok(())
+    }
+
+    fn enable_mouse_capture(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn disable_mouse_capture(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    fn move_to_column(&self, y: u16) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.x = y.min(screen.width.saturating_sub(1));
+        Ok(())
+    }
+
+    fn move_to(&self, x: u16, y: u16) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.x = x.min(screen.width.saturating_sub(1));
+        screen.y = y.min(screen.height.saturating_sub(1));
+        Ok(())
+    }
+
+    fn cursor_position(&self) -> Result<(u16, u16), std::io::Error> {
+        let screen = self.screen.lock().unwrap();
+        Ok((screen.x, screen.y))
+    }
+
+    fn clear_all(&self) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.cells = Screen::blank(screen.width, screen.height);
+        screen.x = 0;
+        screen.y = 0;
+        Ok(())
+    }
+
+    fn clear_current_line(&self) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        let y = screen.y;
+        screen.clear_row(y);
+        Ok(())
+    }
+
+    fn clear_current_line_and_move_previous(&self) -> Result<(), std::io::Error> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.x = 0;
+        screen.y = screen.y.saturating_sub(1);
+        let y = screen.y;
+        screen.clear_row(y);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_the_edge_and_scrolls_at_the_bottom() {
+        let term = BufferTerminal::new(3, 2);
+        term.print("abcdef\r\nghi").unwrap();
+        assert_eq!(term.contents(), "def\nghi");
+    }
+
+    #[test]
+    fn drops_styles_and_keeps_wide_graphemes() {
+        let term = BufferTerminal::new(6, 1);
+        term.print("\x1b[31mあい\x1b[0m!").unwrap();
+        assert_eq!(term.contents(), "あい!");
+    }
+
+    #[test]
+    fn alt_screen_restores_main_screen() {
+        let term = BufferTerminal::new(4, 1);
+        term.print("main").unwrap();
+        term.enter_alt_screen().unwrap();
+        term.clear_all().unwrap();
+        term.print("alt").unwrap();
+        assert_eq!(term.contents(), "alt");
+        term.leave_alt_screen().unwrap();
+        assert_eq!(term.contents(), "main");
+    }
+}
@@ -0,0 +1,225 @@
+//! Helpers for adapting colors to what the terminal can display.
+
+use crossterm::style::Color;
+
+/// Color support detected by [`crate::Program`] at startup.
+///
+/// The program stores it in [`crate::Extensions`] before calling [`crate::Model::init`]
+/// unless one was inserted already, so models can read it with
+/// `input.extensions.get::<ColorCapability>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorCapability {
+    /// Whether 24-bit colors are supported.
+    pub truecolor: bool,
+}
+
+impl ColorCapability {
+    /// Detect the capability of the current terminal, see [`supports_truecolor`].
+    pub fn detect() -> Self {
+        Self {
+            truecolor: supports_truecolor(),
+        }
+    }
+}
+
+/// Return whether the terminal advertises 24-bit color support.
+///
+/// This checks `COLORTERM` for `truecolor`/`24bit` and `TERM` for a `truecolor`,
+/// `24bit` or `direct` suffix.
+pub fn supports_truecolor() -> bool {
+    detect_truecolor(
+        std::env::var("COLORTERM").ok().as_deref(),
+        std::env::var("TERM").ok().as_deref(),
+    )
+}
+
+fn detect_truecolor(colorterm: Option<&str>, term: Option<&str>) -> bool {
+    if let Some(colorterm) = colorterm {
+        let colorterm = colorterm.to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return true;
+        }
+    }
+    term.is_some_and(|term| {
+        let term = term.to_ascii_lowercase();
+        ["truecolor", "24bit", "direct"]
+            .iter()
+            .any(|suffix| term.ends_with(suffix))
+    })
+}
+
+/// Levels of each channel in the 6x6x6 color cube of the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Map a [`Color::Rgb`] to the nearest [`Color::AnsiValue`] of the 256-color palette.
+///
+/// Both the color cube (16-231) and the grayscale ramp (232-255) are considered.
+/// Other colors are returned unchanged.
+pub fn to_nearest_ansi256(color: Color) -> Color {
+    let Color::Rgb { r, g, b } = color else {
+        return color;
+    };
+
+    let nearest_level = |v: u8| -> usize {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - v as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray_value = 8 + 10 * gray_step;
+    let gray = (gray_value, gray_value, gray_value);
+    let gray_index = 232 + gray_step as usize;
+
+    let distance = |(cr, cg, cb): (u8, u8, u8)| -> i32 {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(cr, r) + d(cg, g) + d(cb, b)
+    };
+    let index = if distance(gray) < distance(cube) {
+        gray_index
+    } else {
+        cube_index
+    };
+    Color::AnsiValue(index as u8)
+}
+
+/// The 16 basic colors as xterm renders them, indexed like [`Color::AnsiValue`].
+const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Return the RGB value of `color`, or `None` for [`Color::Reset`].
+fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Rgb { r, g, b } => return Some((r, g, b)),
+        Color::AnsiValue(v) => v,
+        Color::Reset => return None,
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 11,
+        Color::Blue => 12,
+        Color::Magenta => 13,
+        Color::Cyan => 14,
+        Color::White => 15,
+    };
+    Some(match index {
+        0..=15 => BASIC_COLORS[index as usize],
+        16..=231 => {
+            let i = index as usize - 16;
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[i / 6 % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        _ => {
+            let v = 8 + 10 * (index - 232);
+            (v, v, v)
+        }
+    })
+}
+
+/// Return black or white, whichever is easier to read on `bg`.
+///
+/// The choice is based on the perceived brightness of `bg`, so it works for selection
+/// styling across light and dark themes. [`Color::Reset`] has no known value and gives
+/// [`Color::Reset`] back, i.e. the terminal's default foreground.
+///
+/// # Examples
+///
+/// ```
+/// use matcha::{readable_fg, Color};
+///
+/// assert_eq!(readable_fg(Color::Yellow), Color::Black);
+/// assert_eq!(readable_fg(Color::Rgb { r: 30, g: 30, b: 60 }), Color::White);
+/// ```
+pub fn readable_fg(bg: Color) -> Color {
+    let Some((r, g, b)) = to_rgb(bg) else {
+        return Color::Reset;
+    };
+    let brightness = (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000;
+    if brightness >= 128 {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_truecolor_from_colorterm_or_term() {
+        assert!(detect_truecolor(Some("truecolor"), Some("xterm-256color")));
+        assert!(detect_truecolor(Some("24bit"), None));
+        assert!(detect_truecolor(None, Some("xterm-direct")));
+        assert!(!detect_truecolor(None, Some("xterm-256color")));
+        assert!(!detect_truecolor(Some(""), None));
+    }
+
+    #[test]
+    fn rgb_maps_to_nearest_palette_entry() {
+        let ansi = |r, g, b| to_nearest_ansi256(Color::Rgb { r, g, b });
+        assert_eq!(ansi(0, 0, 0), Color::AnsiValue(16));
+        assert_eq!(ansi(255, 255, 255), Color::AnsiValue(231));
+        assert_eq!(ansi(255, 0, 0), Color::AnsiValue(196));
+        assert_eq!(ansi(128, 128, 128), Color::AnsiValue(244));
+        assert_eq!(ansi(0x7D, 0x56, 0xF4), Color::AnsiValue(99));
+        assert_eq!(to_nearest_ansi256(Color::Red), Color::Red);
+    }
+
+    #[test]
+    fn dark_backgrounds_get_white_text() {
+        assert_eq!(readable_fg(Color::Black), Color::White);
+        assert_eq!(readable_fg(Color::DarkBlue), Color::White);
+        assert_eq!(readable_fg(Color::AnsiValue(17)), Color::White);
+        assert_eq!(readable_fg(Color::AnsiValue(236)), Color::White);
+        assert_eq!(readable_fg(Color::Rgb { r: 40, g: 0, b: 80 }), Color::White);
+    }
+
+    #[test]
+    fn light_backgrounds_get_black_text() {
+        assert_eq!(readable_fg(Color::White), Color::Black);
+        assert_eq!(readable_fg(Color::Yellow), Color::Black);
+        assert_eq!(readable_fg(Color::AnsiValue(230)), Color::Black);
+        assert_eq!(readable_fg(Color::AnsiValue(252)), Color::Black);
+        assert_eq!(
+            readable_fg(Color::Rgb {
+                r: 240,
+                g: 240,
+                b: 200
+            }),
+            Color::Black
+        );
+        assert_eq!(readable_fg(Color::Reset), Color::Reset);
+    }
+}
@@ -105,4 +105,148 @@ impl Extensions {
     {
         self.get::<T>().unwrap()
     }
+
+    /// Retrieves a mutable reference to a value of type `T`.
+    ///
+    /// Values are shared with clones of this container (for example the ones handed to
+    /// [`crate::Model::execute`]), so a mutable reference is only available while this
+    /// container is the only owner. Returns `None` if the value is missing or shared.
+    /// Use interior mutability (e.g. a `Mutex`) for state that changes while the
+    /// program runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matcha::Extensions;
+    ///
+    /// let mut extensions = Extensions::new();
+    /// extensions.insert(1u32);
+    /// *extensions.get_mut::<u32>().unwrap() += 1;
+    /// assert_eq!(extensions.get::<u32>(), Some(&2));
+    ///
+    /// let shared = extensions.clone();
+    /// assert!(extensions.get_mut::<u32>().is_none());
+    /// drop(shared);
+    /// assert!(extensions.get_mut::<u32>().is_some());
+    /// ```
+    pub fn get_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: 'static,
+    {
+        let item = self.0.get_mut(&TypeId::of::<T>())?;
+        Arc::get_mut(item).and_then(|any| any.downcast_mut())
+    }
+
+    /// Removes a value of type `T` from the container and returns it.
+    ///
+    /// The value is removed even if a clone of this container still shares it, but it
+    /// can only be returned when this container was its last owner. Returns `None`
+    /// if the value is missing or shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matcha::Extensions;
+    ///
+    /// let mut extensions = Extensions::new();
+    /// extensions.insert(42u32);
+    /// assert_eq!(extensions.remove::<u32>(), Some(42));
+    /// assert!(!extensions.contains::<u32>());
+    /// ```
+    pub fn remove<T>(&mut self) -> Option<T>
+    where
+        T: 'static,
+    {
+        let item = self.0.remove(&TypeId::of::<T>())?;
+        let any = Arc::try_unwrap(item).ok()?;
+        let any: Box<dyn Any> = any;
+        any.downcast().ok().map(|item| *item)
+    }
+
+    /// Returns whether a value of type `T` is stored in the container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matcha::Extensions;
+    ///
+    /// let mut extensions = Extensions::new();
+    /// extensions.insert(42u32);
+    /// assert!(extensions.contains::<u32>());
+    /// assert!(!extensions.contains::<u64>());
+    /// ```
+    pub fn contains<T>(&self) -> bool
+    where
+        T: 'static,
+    {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Config(&'static str);
+
+    #[test]
+    fn insert_replaces_an_existing_value() {
+        let mut extensions = Extensions::new();
+        extensions.insert(Config("old"));
+        extensions.insert(Config("new"));
+        assert_eq!(extensions.get::<Config>(), Some(&Config("new")));
+        assert_eq!(extensions.0.len(), 1);
+    }
+
+    #[test]
+    fn get_mut_changes_the_stored_value() {
+        let mut extensions = Extensions::new();
+        assert!(extensions.get_mut::<Config>().is_none());
+
+        extensions.insert(Config("old"));
+        extensions.get_mut::<Config>().unwrap().0 = "new";
+        assert_eq!(extensions.get::<Config>(), Some(&Config("new")));
+    }
+
+    #[test]
+    fn get_mut_is_none_while_shared() {
+        let mut extensions = Extensions::new();
+        extensions.insert(Config("shared"));
+        let clone = extensions.clone();
+        assert!(extensions.get_mut::<Config>().is_none());
+        drop(clone);
+        assert!(extensions.get_mut::<Config>().is_some());
+    }
+
+    #[test]
+    fn remove_returns_the_value_once() {
+        let mut extensions = Extensions::new();
+        extensions.insert(Config("value"));
+        extensions.insert(7u32);
+
+        assert_eq!(extensions.remove::<Config>(), Some(Config("value")));
+        assert!(!extensions.contains::<Config>());
+        assert_eq!(extensions.remove::<Config>(), None);
+        assert!(extensions.contains::<u32>());
+    }
+
+    #[test]
+    fn remove_missing_type_is_none() {
+        let mut extensions = Extensions::new();
+        extensions.insert(7u32);
+        assert_eq!(extensions.remove::<Config>(), None);
+        assert_eq!(extensions.get::<u32>(), Some(&7));
+    }
+
+    #[test]
+    fn remove_shared_value_drops_it_without_returning_it() {
+        let mut extensions = Extensions::new();
+        extensions.insert(Config("shared"));
+        let clone = extensions.clone();
+
+        assert_eq!(extensions.remove::<Config>(), None);
+        assert!(!extensions.contains::<Config>());
+        assert_eq!(clone.get::<Config>(), Some(&Config("shared")));
+    }
 }
@@ -1,8 +1,77 @@
 use std::{fmt::Display, rc::Rc};
 
-use unicode_segmentation::UnicodeSegmentation;
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
 use unicode_width::UnicodeWidthStr;
 
+/// Append the escape sequence whose `ESC` was just read from `graphemes` to `seq`.
+///
+/// CSI sequences (`ESC [`) end at their final byte. OSC sequences (`ESC ]`), such as
+/// `ESC ]8;;URL ST` hyperlinks, end at BEL or ST (`ESC \`).
+///
+/// Use this when walking a string grapheme by grapheme to copy escape sequences through
+/// without counting them towards the displayed width.
+///
+/// ```
+/// use unicode_segmentation::UnicodeSegmentation;
+///
+/// let mut graphemes = "\x1b]8;;https://example.com\x1b\\link".graphemes(true);
+/// graphemes.next();
+/// let mut seq = String::new();
+/// matcha::push_escape_sequence(&mut graphemes, &mut seq);
+/// assert_eq!(seq, "\x1b]8;;https://example.com\x1b\\");
+/// assert_eq!(graphemes.as_str(), "link");
+/// ```
+pub fn push_escape_sequence(graphemes: &mut Graphemes<'_>, seq: &mut String) {
+    seq.push('\x1b');
+    let Some(introducer) = graphemes.next() else {
+        return;
+    };
+    seq.push_str(introducer);
+    #[allow(clippy::while_let_on_iterator)]
+    while let Some(grapheme) = graphemes.next() {
+        seq.push_str(grapheme);
+        if introducer == "]" {
+            if grapheme == "\x07" {
+                break;
+            }
+            if grapheme == "\x1b" {
+                if let Some(grapheme) = graphemes.next() {
+                    seq.push_str(grapheme);
+                }
+                break;
+            }
+        } else if matches!(
+            grapheme.as_bytes().first(),
+            Some(0x40..=0x5c) | Some(0x61..=0x7a)
+        ) {
+            break;
+        }
+    }
+}
+
+/// Display width of a single grapheme cluster.
+///
+/// Zero-width joiners, skin-tone modifiers and combining marks add no width to the
+/// grapheme they belong to, so a cluster never takes more than two columns. A variation
+/// selector 16 (U+FE0F) asks for emoji presentation, which terminals draw two columns wide.
+pub(crate) fn grapheme_display_width(g: &str) -> u16 {
+    if g.contains('\u{fe0f}') {
+        return 2;
+    }
+    g.width().min(2) as u16
+}
+
+/// Display width of `s`, ignoring ANSI escape sequences.
+///
+/// Graphemes are measured the way [`wrap`] and [`wrap_words`] measure them, so a
+/// variation selector 16 (U+FE0F) makes an emoji two columns wide.
+pub fn display_width(s: &str) -> u16 {
+    remove_escape_sequences(s)
+        .graphemes(true)
+        .map(grapheme_display_width)
+        .fold(0, u16::saturating_add)
+}
+
 /// Wrap a string into lines with a maximum display width.
 ///
 /// This function is *ANSI-aware*: it tries to preserve ANSI escape sequences without
@@ -17,23 +86,9 @@ pub fn wrap(s: &str, max_width: u16) -> Vec<String> {
 
     while let Some(grapheme) = graphemes.next() {
         if grapheme == "\x1b" {
-            result[index].push_str(grapheme);
-            // `[`
-            if let Some(grapheme) = graphemes.next() {
-                result[index].push_str(grapheme);
-            }
-            #[allow(clippy::while_let_on_iterator)]
-            while let Some(grapheme) = graphemes.next() {
-                result[index].push_str(grapheme);
-                if matches!(
-                    grapheme.as_bytes().first(),
-                    Some(0x40..=0x5c) | Some(0x61..=0x7a)
-                ) {
-                    break;
-                }
-            }
+            push_escape_sequence(&mut graphemes, &mut result[index]);
         } else {
-            let grapheme_width = grapheme.width() as u16;
+            let grapheme_width = grapheme_display_width(grapheme);
             if width + grapheme_width > max_width {
                 index += 1;
                 result.push(String::new());
@@ -46,6 +101,211 @@ pub fn wrap(s: &str, max_width: u16) -> Vec<String> {
     result
 }
 
+/// Options for [`wrap_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// Keep whitespace at the end of each line. When `false`, trailing whitespace is
+    /// trimmed, which suits prose; code usually wants it preserved.
+    pub preserve_trailing_ws: bool,
+    /// Break lines at exactly `max_width`, even in the middle of a word. When `false`,
+    /// lines only break at whitespace and a word wider than `max_width` overflows its line.
+    pub break_long_words: bool,
+}
+
+impl Default for WrapOptions {
+    fn default() -> Self {
+        Self {
+            preserve_trailing_ws: true,
+            break_long_words: true,
+        }
+    }
+}
+
+/// Wrap a string into lines with a maximum display width, controlled by `opts`.
+///
+/// [`wrap`] is this function with the default options. Like [`wrap`], this function is
+/// *ANSI-aware*.
+///
+/// The return value is a vector of lines (without trailing `\n`).
+pub fn wrap_with(s: &str, max_width: u16, opts: WrapOptions) -> Vec<String> {
+    let lines = if opts.break_long_words {
+        wrap(s, max_width)
+    } else {
+        wrap_at_whitespace(s, max_width, opts.preserve_trailing_ws, false)
+    };
+    if opts.preserve_trailing_ws {
+        return lines;
+    }
+    lines
+        .into_iter()
+        .map(|line| {
+            let (body, trailing) = split_trailing_escapes(&line);
+            format!("{}{}", body.trim_end(), trailing)
+        })
+        .collect()
+}
+
+/// Wrap a string into lines with a maximum display width, breaking at whitespace.
+///
+/// Words are kept together when possible; only a single word wider than `max_width`
+/// is broken mid-word. Whitespace at a line break is dropped. Like [`wrap`], this
+/// function is *ANSI-aware*: escape sequences are preserved, never split and do not
+/// count towards the displayed width.
+///
+/// The return value is a vector of lines (without trailing `\n`).
+pub fn wrap_words(s: &str, max_width: u16) -> Vec<String> {
+    wrap_at_whitespace(s, max_width, false, true)
+}
+
+enum WrapToken<'a> {
+    Escape(String),
+    Space(&'a str),
+    Char(&'a str),
+}
+
+impl WrapToken<'_> {
+    fn width(&self) -> u16 {
+        match self {
+            WrapToken::Space(g) | WrapToken::Char(g) => grapheme_display_width(g),
+            WrapToken::Escape(_) => 0,
+        }
+    }
+}
+
+/// Append a whitespace run to `line`, keeping escape sequences and the spaces that fit.
+fn push_gap(line: &mut String, width: &mut u16, gap: &[WrapToken], spaces: bool, max_width: u16) {
+    for token in gap {
+        match token {
+            WrapToken::Escape(seq) => line.push_str(seq),
+            WrapToken::Space(g) if spaces && *width + token.width() <= max_width => {
+                line.push_str(g);
+                *width += token.width();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Greedy word wrapping shared by [`wrap_words`] and [`wrap_with`].
+///
+/// `keep_break_ws` keeps the whitespace at a break at the end of the previous line;
+/// `break_long_words` splits words wider than `max_width`.
+fn wrap_at_whitespace(
+    s: &str,
+    max_width: u16,
+    keep_break_ws: bool,
+    break_long_words: bool,
+) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut graphemes = s.graphemes(true);
+    while let Some(grapheme) = graphemes.next() {
+        if grapheme == "\x1b" {
+            let mut seq = String::new();
+            push_escape_sequence(&mut graphemes, &mut seq);
+            tokens.push(WrapToken::Escape(seq));
+        } else if grapheme.chars().all(char::is_whitespace) {
+            tokens.push(WrapToken::Space(grapheme));
+        } else {
+            tokens.push(WrapToken::Char(grapheme));
+        }
+    }
+
+    let mut result = vec![String::new()];
+    let mut width: u16 = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        // Collect the whitespace run (gap) and the following word.
+        let gap_start = i;
+        while i < tokens.len() && !matches!(tokens[i], WrapToken::Char(_)) {
+            i += 1;
+        }
+        let word_start = i;
+        while i < tokens.len() && !matches!(tokens[i], WrapToken::Space(_)) {
+            i += 1;
+        }
+        let (gap, word) = (&tokens[gap_start..word_start], &tokens[word_start..i]);
+        let gap_width: u16 = gap.iter().map(WrapToken::width).sum();
+        let word_width: u16 = word.iter().map(WrapToken::width).sum();
+
+        let breaks = !word.is_empty() && width > 0 && width + gap_width + word_width > max_width;
+        if breaks && keep_break_ws {
+            push_gap(result.last_mut().unwrap(), &mut width, gap, true, max_width);
+            result.push(String::new());
+            width = 0;
+        } else {
+            if breaks {
+                result.push(String::new());
+                width = 0;
+            }
+            // Leading whitespace is kept on the first line only; whitespace at a line
+            // break (or overflowing a trailing gap) is dropped.
+            let spaces = width > 0 || result.len() == 1;
+            push_gap(
+                result.last_mut().unwrap(),
+                &mut width,
+                gap,
+                spaces,
+                max_width,
+            );
+        }
+        for token in word {
+            match token {
+                WrapToken::Escape(seq) => result.last_mut().unwrap().push_str(seq),
+                WrapToken::Char(g) | WrapToken::Space(g) => {
+                    // Only a word wider than `max_width` gets here with a full line.
+                    let w = token.width();
+                    if break_long_words && width > 0 && width + w > max_width {
+                        result.push(String::new());
+                        width = 0;
+                    }
+                    result.last_mut().unwrap().push_str(g);
+                    width += w;
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Split `s` into its body and the escape sequences at its very end.
+fn split_trailing_escapes(s: &str) -> (&str, &str) {
+    let mut body = s;
+    while let Some(index) = body.rfind('\x1b') {
+        if !remove_escape_sequences(&body[index..]).is_empty() {
+            break;
+        }
+        body = &body[..index];
+    }
+    s.split_at(body.len())
+}
+
+/// Expand each `\t` to spaces up to the next multiple of `tab_width` columns.
+///
+/// This function is *ANSI-aware*: escape sequences are preserved and do not advance the
+/// column. A `tab_width` of `0` removes tabs.
+pub fn expand_tabs(s: &str, tab_width: u16) -> String {
+    let mut column: usize = 0;
+    let mut result = String::new();
+    let mut graphemes = s.graphemes(true);
+
+    while let Some(grapheme) = graphemes.next() {
+        if grapheme == "\x1b" {
+            push_escape_sequence(&mut graphemes, &mut result);
+        } else if grapheme == "\t" {
+            if tab_width == 0 {
+                continue;
+            }
+            let spaces = tab_width as usize - column % tab_width as usize;
+            result.push_str(&" ".repeat(spaces));
+            column += spaces;
+        } else {
+            result.push_str(grapheme);
+            column += grapheme_display_width(grapheme) as usize;
+        }
+    }
+    result
+}
+
 /// Clamp a string to a maximum display width.
 ///
 /// This function is *ANSI-aware*: it preserves escape sequences while ensuring the
@@ -59,26 +319,12 @@ pub fn clamp_by(s: &str, max_width: u16) -> String {
 
     while let Some(grapheme) = graphemes.next() {
         if grapheme == "\x1b" {
-            result.push_str(grapheme);
-            // `[`
-            if let Some(grapheme) = graphemes.next() {
-                result.push_str(grapheme);
-            }
-            #[allow(clippy::while_let_on_iterator)]
-            while let Some(grapheme) = graphemes.next() {
-                result.push_str(grapheme);
-                if matches!(
-                    grapheme.as_bytes().first(),
-                    Some(0x40..=0x5c) | Some(0x61..=0x7a)
-                ) {
-                    break;
-                }
-            }
+            push_escape_sequence(&mut graphemes, &mut result);
         } else {
             if clamped {
                 continue;
             }
-            let grapheme_width = grapheme.width() as u16;
+            let grapheme_width = grapheme_display_width(grapheme);
             if width + grapheme_width > max_width {
                 clamped = true;
             } else {
@@ -90,11 +336,30 @@ pub fn clamp_by(s: &str, max_width: u16) -> String {
     result
 }
 
+/// Clamp a string to a maximum display width, marking truncation with `ellipsis`.
+///
+/// Strings that already fit are returned unchanged. Otherwise the text is clamped to leave
+/// room for `ellipsis`, which is inserted before any trailing escape sequences (such as a
+/// reset) so it keeps the surrounding style. If `max_width` is smaller than the ellipsis
+/// itself, the clamped ellipsis is returned.
+pub fn truncate(s: &str, max_width: u16, ellipsis: &str) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    let ellipsis_width = display_width(ellipsis);
+    if max_width < ellipsis_width {
+        return clamp_by(ellipsis, max_width);
+    }
+    let clamped = clamp_by(s, max_width - ellipsis_width);
+    let (body, trailing) = split_trailing_escapes(&clamped);
+    format!("{body}{ellipsis}{trailing}")
+}
+
 /// Pad `target` with spaces so its visible width becomes `max_width`.
 ///
 /// The width calculation ignores ANSI escape sequences.
 pub fn fill_by_space(target: String, max_width: u16) -> String {
-    let d = max_width.saturating_sub(remove_escape_sequences(&target).width() as u16);
+    let d = max_width.saturating_sub(display_width(&target));
     if d != 0 {
         format!("{}{}", target, " ".repeat(d as usize))
     } else {
@@ -102,6 +367,62 @@ pub fn fill_by_space(target: String, max_width: u16) -> String {
     }
 }
 
+/// Pad `target` with spaces on the left so its visible width becomes `max_width`.
+///
+/// The width calculation ignores ANSI escape sequences.
+pub fn pad_left(target: String, max_width: u16) -> String {
+    let d = max_width.saturating_sub(display_width(&target));
+    if d != 0 {
+        format!("{}{}", " ".repeat(d as usize), target)
+    } else {
+        target
+    }
+}
+
+/// Pad `target` with spaces on both sides so its visible width becomes `max_width`.
+///
+/// When the padding is odd, the extra space goes to the right. The width calculation
+/// ignores ANSI escape sequences.
+pub fn pad_center(target: String, max_width: u16) -> String {
+    let d = max_width.saturating_sub(display_width(&target));
+    if d != 0 {
+        let left = d / 2;
+        format!(
+            "{}{}{}",
+            " ".repeat(left as usize),
+            target,
+            " ".repeat((d - left) as usize)
+        )
+    } else {
+        target
+    }
+}
+
+/// Horizontal alignment used by [`align`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    /// Pad on the right.
+    #[default]
+    Left,
+    /// Pad on both sides.
+    Center,
+    /// Pad on the left.
+    Right,
+}
+
+/// Pad `s` with spaces to `max_width` according to `align`.
+///
+/// The width calculation ignores ANSI escape sequences. Strings that already meet or
+/// exceed `max_width` are returned unchanged.
+pub fn align(s: &str, max_width: u16, align: Align) -> String {
+    let s = s.to_string();
+    match align {
+        Align::Left => fill_by_space(s, max_width),
+        Align::Center => pad_center(s, max_width),
+        Align::Right => pad_left(s, max_width),
+    }
+}
+
 /// Remove ANSI escape sequences from `text`.
 ///
 /// This is useful when you need to measure the "visible" width of styled strings.
@@ -111,20 +432,7 @@ pub fn remove_escape_sequences(text: &str) -> String {
 
     while let Some(g) = graphemes.next() {
         if g == "\x1b" {
-            if let Some(grapheme) = graphemes.next() {
-                if grapheme != "[" {
-                    break;
-                }
-            }
-            #[allow(clippy::while_let_on_iterator)]
-            while let Some(grapheme) = graphemes.next() {
-                if matches!(
-                    grapheme.as_bytes().first(),
-                    Some(0x40..=0x5c) | Some(0x61..=0x7a)
-                ) {
-                    break;
-                }
-            }
+            push_escape_sequence(&mut graphemes, &mut String::new());
         } else {
             result += g;
         }
@@ -183,4 +491,150 @@ mod tests {
         let removed = remove_escape_sequences(input);
         assert_eq!(removed, "こんにちは!いい天気ですね");
     }
+
+    #[test]
+    fn test_clamp_keeps_hyperlink_terminators() {
+        let input = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ after";
+        assert_eq!(
+            clamp_by(input, 2),
+            "\x1b]8;;https://example.com\x1b\\li\x1b]8;;\x1b\\"
+        );
+        let input = "\x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+        assert_eq!(
+            clamp_by(input, 2),
+            "\x1b]8;;https://example.com\x07li\x1b]8;;\x07"
+        );
+    }
+
+    #[test]
+    fn test_remove_escape_sequences_with_hyperlink() {
+        let input = "a \x1b]8;;https://example.com\x1b\\\x1b[31mlink\x1b[0m\x1b]8;;\x1b\\ b";
+        assert_eq!(remove_escape_sequences(input), "a link b");
+    }
+
+    #[test]
+    fn test_wrap_with_hyperlink() {
+        let input = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\";
+        assert_eq!(
+            wrap(input, 2),
+            vec!["\x1b]8;;https://example.com\x1b\\li", "nk\x1b]8;;\x1b\\"]
+        );
+        assert_eq!(
+            wrap_words("\x1b]8;;https://example.com\x07a link\x1b]8;;\x07", 4),
+            vec!["\x1b]8;;https://example.com\x07a", "link\x1b]8;;\x07"]
+        );
+    }
+
+    #[test]
+    fn test_grapheme_display_width() {
+        assert_eq!(grapheme_display_width("👨\u{200d}👩\u{200d}👧"), 2);
+        assert_eq!(grapheme_display_width("👍🏽"), 2);
+        assert_eq!(grapheme_display_width("🇯🇵"), 2);
+        assert_eq!(grapheme_display_width("e\u{301}"), 1);
+        assert_eq!(grapheme_display_width("\u{200d}"), 0);
+    }
+
+    #[test]
+    fn test_emoji_presentation_selector_is_two_columns() {
+        assert_eq!(grapheme_display_width("\u{2764}\u{fe0f}"), 2);
+        assert_eq!(grapheme_display_width("\u{263a}\u{fe0f}"), 2);
+        assert_eq!(grapheme_display_width("\u{2764}"), 1);
+        assert_eq!(
+            fill_by_space("\u{2764}\u{fe0f}".to_string(), 4),
+            "\u{2764}\u{fe0f}  "
+        );
+        assert_eq!(clamp_by("\u{263a}\u{fe0f}a", 2), "\u{263a}\u{fe0f}");
+    }
+
+    #[test]
+    fn test_format_does_not_over_truncate_clusters() {
+        assert_eq!(
+            format("👨\u{200d}👩\u{200d}👧ab", (4, 1)),
+            "👨\u{200d}👩\u{200d}👧ab"
+        );
+        assert_eq!(format("🇯🇵🇺🇸", (4, 1)), "🇯🇵🇺🇸");
+        assert_eq!(format("e\u{301}e\u{301}", (3, 1)), "e\u{301}e\u{301} ");
+        assert_eq!(wrap("👍🏽👍🏽", 2), vec!["👍🏽", "👍🏽"]);
+    }
+
+    #[test]
+    fn test_truncate_appends_ellipsis_before_reset() {
+        let input = "\x1b[31mHello, World!\x1b[0m";
+        assert_eq!(truncate(input, 8, "…"), "\x1b[31mHello, \u{2026}\x1b[0m");
+        assert_eq!(truncate(input, 13, "…"), input);
+    }
+
+    #[test]
+    fn test_truncate_narrower_than_ellipsis() {
+        assert_eq!(truncate("Hello", 2, "..."), "..");
+    }
+
+    #[test]
+    fn test_align_ignores_escape_sequences() {
+        let input = "\x1b[31mab\x1b[0m";
+        assert_eq!(align(input, 5, Align::Left), format!("{input}   "));
+        assert_eq!(align(input, 5, Align::Center), format!(" {input}  "));
+        assert_eq!(align(input, 5, Align::Right), format!("   {input}"));
+        assert_eq!(align(input, 2, Align::Center), input);
+    }
+
+    #[test]
+    fn test_expand_tabs_to_next_tab_stop() {
+        assert_eq!(expand_tabs("a\tbc\td", 4), "a   bc  d");
+        assert_eq!(
+            expand_tabs("\x1b[31mab\x1b[0m\tc", 4),
+            "\x1b[31mab\x1b[0m  c"
+        );
+        assert_eq!(expand_tabs("\tx", 0), "x");
+    }
+
+    #[test]
+    fn test_wrap_with_trailing_whitespace() {
+        let keep = WrapOptions::default();
+        assert_eq!(wrap_with("ab  cd", 3, keep), wrap("ab  cd", 3));
+        assert_eq!(wrap_with("ab  cd", 3, keep), vec!["ab ", " cd"]);
+
+        let trim = WrapOptions {
+            preserve_trailing_ws: false,
+            ..keep
+        };
+        assert_eq!(
+            wrap_with("\x1b[31mab \x1b[0mcd", 3, trim),
+            vec!["\x1b[31mab\x1b[0m", "cd"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_with_unbreakable_long_word() {
+        let opts = WrapOptions {
+            break_long_words: false,
+            ..WrapOptions::default()
+        };
+        assert_eq!(
+            wrap_with("a abcdefgh b", 4, opts),
+            vec!["a ", "abcdefgh", "b"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_words_breaks_at_whitespace() {
+        let wrapped = wrap_words("the quick brown fox jumps", 10);
+        assert_eq!(wrapped, vec!["the quick", "brown fox", "jumps"]);
+    }
+
+    #[test]
+    fn test_wrap_words_hard_breaks_long_word() {
+        let wrapped = wrap_words("a abcdefghijkl b", 5);
+        assert_eq!(wrapped, vec!["a", "abcde", "fghij", "kl b"]);
+    }
+
+    #[test]
+    fn test_wrap_words_with_escape_sequences() {
+        let input = "\x1b[31mhello\x1b[0m \x1b[32mworld\x1b[0m";
+        let wrapped = wrap_words(input, 7);
+        assert_eq!(
+            wrapped,
+            vec!["\x1b[31mhello\x1b[0m", "\x1b[32mworld\x1b[0m"]
+        );
+    }
 }
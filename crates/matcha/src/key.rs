@@ -1,15 +1,35 @@
 use {
-    ::crossterm::event::KeyEvent,
+    ::crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     crokey::KeyCombination,
-    std::{collections::HashMap, ops::Deref},
+    std::{
+        collections::HashMap,
+        ops::Deref,
+        time::{Duration, Instant},
+    },
 };
 
 /// A structure for storing and retrieving bindings between [`Key`] and arbitrary data.
 ///
 /// This is especially useful for setting up configuration or user-defined key mappings
 /// to certain functionalities within an application.
+///
+/// `keys` holds single-key bindings. Multi-key chords such as `g g` are added with
+/// [`KeyBindings::with_sequence`] (see [`KeyBindings::get_sequence`]).
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct KeyBindings<T>(pub HashMap<Key, T>);
+pub struct KeyBindings<T> {
+    /// Single-key bindings.
+    pub keys: HashMap<Key, T>,
+    sequences: Vec<(KeySequence, T)>,
+}
+
+impl<T> From<HashMap<Key, T>> for KeyBindings<T> {
+    fn from(keys: HashMap<Key, T>) -> Self {
+        Self {
+            keys,
+            sequences: Vec::new(),
+        }
+    }
+}
 
 impl<T> KeyBindings<T> {
     /// Creates a new [`KeyBindings`] instance from a [`HashMap`] of [`KeyCombination`] to `T`.
@@ -51,12 +71,213 @@ impl<T> KeyBindings<T> {
     /// KeyBindings::new(bindings);
     /// ```
     pub fn new(bindings: HashMap<KeyCombination, T>) -> Self {
-        Self(bindings.into_iter().map(|k| (Key(k.0), k.1)).collect())
+        bindings
+            .into_iter()
+            .map(|k| (Key(k.0), k.1))
+            .collect::<HashMap<_, _>>()
+            .into()
     }
 
     /// Get a binding by key.
     pub fn get(&self, k: Key) -> Option<&T> {
-        self.0.get(&k)
+        self.keys.get(&k)
+    }
+
+    /// Bind `key` to `action`, returning the previous action if the key was bound.
+    pub fn insert(&mut self, key: Key, action: T) -> Option<T> {
+        self.keys.insert(key, action)
+    }
+
+    /// Unbind `key`, returning its action if it was bound.
+    pub fn remove(&mut self, key: &Key) -> Option<T> {
+        self.keys.remove(key)
+    }
+
+    /// Return whether `key` is bound.
+    pub fn contains(&self, key: &Key) -> bool {
+        self.keys.contains_key(key)
+    }
+
+    /// Return every single key bound to `action`, sorted by their description.
+    ///
+    /// This is useful to build help views that stay in sync with rebound keys.
+    pub fn keys_for(&self, action: &T) -> Vec<Key>
+    where
+        T: PartialEq,
+    {
+        let mut keys: Vec<Key> = self
+            .keys
+            .iter()
+            .filter(|(_, a)| *a == action)
+            .map(|(k, _)| *k)
+            .collect();
+        keys.sort_by_key(|k| k.describe());
+        keys
+    }
+
+    /// Apply `overrides` on top of these bindings.
+    ///
+    /// Keys and chords bound in `overrides` replace the existing binding; everything
+    /// else is kept. This lets a config file rebind a few keys without redefining all.
+    pub fn merge(mut self, overrides: KeyBindings<T>) -> Self {
+        self.keys.extend(overrides.keys);
+        for (sequence, action) in overrides.sequences {
+            self.insert_sequence(sequence, action);
+        }
+        self
+    }
+
+    /// Bind a multi-key chord, replacing any previous binding of the same chord.
+    pub fn with_sequence(mut self, sequence: KeySequence, action: T) -> Self {
+        self.insert_sequence(sequence, action);
+        self
+    }
+
+    /// Bind a multi-key chord, replacing any previous binding of the same chord.
+    pub fn insert_sequence(&mut self, sequence: KeySequence, action: T) {
+        self.sequences.retain(|(s, _)| s != &sequence);
+        self.sequences.push((sequence, action));
+    }
+
+    /// Match the keys pressed so far against the bindings.
+    ///
+    /// Single-key bindings are treated as chords of length one. An exact match wins over
+    /// a longer chord sharing the same prefix.
+    pub fn get_sequence(&self, keys: &[Key]) -> SequenceMatch<'_, T> {
+        if let [key] = keys {
+            if let Some(action) = self.keys.get(key) {
+                return SequenceMatch::Full(action);
+            }
+        }
+        let mut partial = false;
+        for (sequence, action) in self.sequences.iter() {
+            if sequence.0 == keys {
+                return SequenceMatch::Full(action);
+            }
+            partial |= !keys.is_empty() && sequence.0.starts_with(keys);
+        }
+        if partial {
+            SequenceMatch::Partial
+        } else {
+            SequenceMatch::None
+        }
+    }
+}
+
+/// An ordered chord of keys, e.g. `g g` or `ctrl-x ctrl-c`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct KeySequence(pub Vec<Key>);
+
+impl KeySequence {
+    /// Create a sequence from key combinations, typically built with `key!`.
+    pub fn new(keys: impl IntoIterator<Item = KeyCombination>) -> Self {
+        Self(keys.into_iter().map(Key).collect())
+    }
+}
+
+/// Result of [`KeyBindings::get_sequence`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum SequenceMatch<'a, T> {
+    /// The keys form a complete binding.
+    Full(&'a T),
+    /// The keys are a prefix of at least one chord; wait for more keys.
+    Partial,
+    /// No binding starts with these keys.
+    None,
+}
+
+/// Collects keys pressed in a row and matches them against chords in [`KeyBindings`].
+///
+/// Pending keys are dropped when a key does not continue any chord, or when more than
+/// the timeout has passed since the previous key.
+///
+/// # Examples
+///
+/// A textarea could bind `g g` to jump to the top of the buffer:
+///
+/// ```
+/// use std::time::Duration;
+/// use matcha::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Action {
+///     GoToTop,
+/// }
+///
+/// let bindings = KeyBindings::new(Default::default())
+///     .with_sequence(KeySequence::new([key!(g), key!(g)]), Action::GoToTop);
+///
+/// let mut matcher = KeySequenceMatcher::new(Duration::from_secs(1));
+/// assert_eq!(matcher.feed(&bindings, Key(key!(g))), SequenceMatch::Partial);
+/// assert_eq!(
+///     matcher.feed(&bindings, Key(key!(g))),
+///     SequenceMatch::Full(&Action::GoToTop)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeySequenceMatcher {
+    pending: Vec<Key>,
+    last: Option<Instant>,
+    timeout: Duration,
+}
+
+impl Default for KeySequenceMatcher {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+impl KeySequenceMatcher {
+    /// Create a matcher that forgets pending keys after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            pending: Vec::new(),
+            last: None,
+            timeout,
+        }
+    }
+
+    /// Feed a key press and return how the pending keys match `bindings`.
+    ///
+    /// When the key breaks a pending chord, it is matched again on its own so it can
+    /// start a new chord.
+    pub fn feed<'a, T>(&mut self, bindings: &'a KeyBindings<T>, key: Key) -> SequenceMatch<'a, T> {
+        let now = Instant::now();
+        if self
+            .last
+            .is_some_and(|last| now.duration_since(last) > self.timeout)
+        {
+            self.pending.clear();
+        }
+        self.last = Some(now);
+
+        self.pending.push(key);
+        match bindings.get_sequence(&self.pending) {
+            SequenceMatch::Partial => SequenceMatch::Partial,
+            SequenceMatch::Full(action) => {
+                self.pending.clear();
+                SequenceMatch::Full(action)
+            }
+            SequenceMatch::None if self.pending.len() > 1 => {
+                self.pending.clear();
+                self.feed(bindings, key)
+            }
+            SequenceMatch::None => {
+                self.pending.clear();
+                SequenceMatch::None
+            }
+        }
+    }
+
+    /// Keys pressed so far for an incomplete chord.
+    pub fn pending(&self) -> &[Key] {
+        &self.pending
+    }
+
+    /// Drop any pending keys.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last = None;
     }
 }
 
@@ -102,4 +323,292 @@ impl Key {
     pub fn combination(&self) -> KeyCombination {
         self.0
     }
+
+    /// Render a short human readable label such as `ctrl-b`, `enter` or `↑`.
+    pub fn describe(&self) -> String {
+        let mut label = String::new();
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl-"),
+            (KeyModifiers::ALT, "alt-"),
+            (KeyModifiers::SHIFT, "shift-"),
+        ] {
+            if self.0.modifiers.contains(modifier) {
+                label.push_str(name);
+            }
+        }
+        let codes: Vec<String> = self.0.codes.iter().map(describe_code).collect();
+        label + &codes.join("-")
+    }
+}
+
+fn describe_code(code: &KeyCode) -> String {
+    match code {
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("f{n}"),
+        KeyCode::PageUp => "pgup".to_string(),
+        KeyCode::PageDown => "pgdown".to_string(),
+        code => format!("{:?}", code).to_lowercase(),
+    }
+}
+
+/// Format `(label, keys)` pairs into a help line such as `↑/↓:Navigate • q:Quit`.
+///
+/// Entries without keys are skipped, so unbound actions disappear from the help.
+pub fn help_line<'a>(entries: impl IntoIterator<Item = (&'a str, Vec<Key>)>) -> String {
+    entries
+        .into_iter()
+        .filter(|(_, keys)| !keys.is_empty())
+        .map(|(label, keys)| {
+            let keys: Vec<String> = keys.iter().map(Key::describe).collect();
+            format!("{}:{}", keys.join("/"), label)
+        })
+        .collect::<Vec<_>>()
+        .join(" • ")
+}
+
+/// Keys are written as crokey-style strings such as `"ctrl-b"` or `"up"`, and chords as
+/// space separated keys such as `"g g"`.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{Key, KeyBindings, KeySequence};
+    use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    impl Key {
+        fn to_config_string(self) -> String {
+            self.0.to_string().to_lowercase()
+        }
+
+        fn parse_config_string(s: &str) -> Result<Self, crokey::ParseKeyError> {
+            crokey::parse(s).map(Key)
+        }
+    }
+
+    impl Serialize for Key {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.to_config_string())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Key {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            Key::parse_config_string(&s).map_err(de::Error::custom)
+        }
+    }
+
+    impl<T: Serialize> Serialize for KeyBindings<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(self.keys.len() + self.sequences.len()))?;
+            for (key, action) in self.keys.iter() {
+                map.serialize_entry(&key.to_config_string(), action)?;
+            }
+            for (sequence, action) in self.sequences.iter() {
+                let keys: Vec<String> = sequence.0.iter().map(|k| k.to_config_string()).collect();
+                map.serialize_entry(&keys.join(" "), action)?;
+            }
+            map.end()
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for KeyBindings<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let entries = HashMap::<String, T>::deserialize(deserializer)?;
+            let mut bindings = KeyBindings::from(HashMap::new());
+            for (keys, action) in entries {
+                let keys = keys
+                    .split_whitespace()
+                    .map(Key::parse_config_string)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(de::Error::custom)?;
+                match keys.as_slice() {
+                    [] => return Err(de::Error::custom("empty key binding")),
+                    [key] => {
+                        bindings.keys.insert(*key, action);
+                    }
+                    _ => bindings.insert_sequence(KeySequence(keys), action),
+                }
+            }
+            Ok(bindings)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crokey::key;
+
+    #[derive(Debug, PartialEq)]
+    enum Action {
+        Quit,
+        Top,
+        Save,
+    }
+
+    fn bindings() -> KeyBindings<Action> {
+        KeyBindings::new([(key!(ctrl - s), Action::Save)].into_iter().collect())
+            .with_sequence(KeySequence::new([key!(g), key!(g)]), Action::Top)
+            .with_sequence(
+                KeySequence::new([key!(ctrl - x), key!(ctrl - c)]),
+                Action::Quit,
+            )
+    }
+
+    #[test]
+    fn describe_renders_short_labels() {
+        assert_eq!(Key(key!(ctrl - b)).describe(), "ctrl-b");
+        assert_eq!(Key(key!(up)).describe(), "↑");
+        assert_eq!(Key(key!(enter)).describe(), "enter");
+        assert_eq!(Key(key!(alt - pagedown)).describe(), "alt-pgdown");
+    }
+
+    #[test]
+    fn keys_for_builds_help_line() {
+        let bindings = KeyBindings::new(
+            [
+                (key!(q), Action::Quit),
+                (key!(ctrl - c), Action::Quit),
+                (key!(ctrl - s), Action::Save),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            bindings.keys_for(&Action::Quit),
+            vec![Key(key!(ctrl - c)), Key(key!(q))]
+        );
+        assert!(bindings.keys_for(&Action::Top).is_empty());
+
+        let help = help_line([
+            ("Save", bindings.keys_for(&Action::Save)),
+            ("Top", bindings.keys_for(&Action::Top)),
+            ("Quit", bindings.keys_for(&Action::Quit)),
+        ]);
+        assert_eq!(help, "ctrl-s:Save • ctrl-c/q:Quit");
+    }
+
+    #[test]
+    fn get_sequence_reports_full_partial_and_none() {
+        let bindings = bindings();
+        let g = Key(key!(g));
+        assert_eq!(bindings.get_sequence(&[g]), SequenceMatch::Partial);
+        assert_eq!(
+            bindings.get_sequence(&[g, g]),
+            SequenceMatch::Full(&Action::Top)
+        );
+        assert_eq!(
+            bindings.get_sequence(&[Key(key!(ctrl - s))]),
+            SequenceMatch::Full(&Action::Save)
+        );
+        assert_eq!(
+            bindings.get_sequence(&[g, Key(key!(x))]),
+            SequenceMatch::None
+        );
+    }
+
+    #[test]
+    fn matcher_restarts_chord_on_non_matching_key() {
+        let bindings = bindings();
+        let mut matcher = KeySequenceMatcher::default();
+        assert_eq!(
+            matcher.feed(&bindings, Key(key!(ctrl - x))),
+            SequenceMatch::Partial
+        );
+        // `g` breaks the pending chord and starts a new one.
+        assert_eq!(
+            matcher.feed(&bindings, Key(key!(g))),
+            SequenceMatch::Partial
+        );
+        assert_eq!(matcher.pending(), &[Key(key!(g))]);
+        assert_eq!(
+            matcher.feed(&bindings, Key(key!(g))),
+            SequenceMatch::Full(&Action::Top)
+        );
+        assert!(matcher.pending().is_empty());
+    }
+
+    #[test]
+    fn matcher_resets_after_timeout() {
+        let bindings = bindings();
+        let mut matcher = KeySequenceMatcher::new(Duration::from_millis(10));
+        assert_eq!(
+            matcher.feed(&bindings, Key(key!(g))),
+            SequenceMatch::Partial
+        );
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(
+            matcher.feed(&bindings, Key(key!(g))),
+            SequenceMatch::Partial
+        );
+    }
+
+    #[test]
+    fn from_map_keeps_single_key_bindings() {
+        let keys: HashMap<Key, Action> = [(Key(key!(q)), Action::Quit)].into_iter().collect();
+        let bindings = KeyBindings::from(keys);
+        assert_eq!(bindings.get(Key(key!(q))), Some(&Action::Quit));
+        assert_eq!(
+            bindings.get_sequence(&[Key(key!(q))]),
+            SequenceMatch::Full(&Action::Quit)
+        );
+    }
+
+    #[test]
+    fn insert_remove_and_contains_rebind_keys() {
+        let mut bindings = KeyBindings::new([(key!(q), Action::Quit)].into_iter().collect());
+        assert!(bindings.contains(&Key(key!(q))));
+
+        assert_eq!(bindings.remove(&Key(key!(q))), Some(Action::Quit));
+        assert!(!bindings.contains(&Key(key!(q))));
+        assert_eq!(bindings.remove(&Key(key!(q))), None);
+
+        assert_eq!(bindings.insert(Key(key!(ctrl - c)), Action::Quit), None);
+        assert_eq!(
+            bindings.insert(Key(key!(ctrl - c)), Action::Save),
+            Some(Action::Quit)
+        );
+        assert_eq!(bindings.get(Key(key!(ctrl - c))), Some(&Action::Save));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bindings_round_trip_through_toml_and_merge() {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        enum Move {
+            Left,
+            Right,
+            Top,
+        }
+
+        let defaults = KeyBindings::new(
+            [(key!(ctrl - b), Move::Left), (key!(ctrl - f), Move::Right)]
+                .into_iter()
+                .collect(),
+        );
+        let config = r#"
+            "left" = "Left"
+            "ctrl-f" = "Left"
+            "g g" = "Top"
+        "#;
+        let overrides: KeyBindings<Move> = toml::from_str(config).unwrap();
+        let merged = defaults.merge(overrides);
+
+        assert_eq!(merged.get(Key(key!(ctrl - b))), Some(&Move::Left));
+        assert_eq!(merged.get(Key(key!(left))), Some(&Move::Left));
+        assert_eq!(merged.get(Key(key!(ctrl - f))), Some(&Move::Left));
+        assert_eq!(
+            merged.get_sequence(&[Key(key!(g)), Key(key!(g))]),
+            SequenceMatch::Full(&Move::Top)
+        );
+
+        let serialized = toml::to_string(&merged).unwrap();
+        let reparsed: KeyBindings<Move> = toml::from_str(&serialized).unwrap();
+        assert_eq!(reparsed, merged);
+    }
 }
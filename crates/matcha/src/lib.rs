@@ -8,25 +8,44 @@
 //! This crate focuses on the runtime/event-loop and basic formatting helpers.
 //! Higher-level UI components live in the companion crate `chagashi`.
 
+mod buffer_terminal;
+pub mod color;
 mod dyn_model;
 mod extension;
 mod formatter;
 mod key;
+mod message_log;
 mod messages;
+mod recording;
 mod termable;
 mod terminal;
+pub mod test;
 
+pub use buffer_terminal::BufferTerminal;
+pub use color::readable_fg;
 pub use dyn_model::{boxed, DynModel};
 pub use extension::*;
 pub use formatter::*;
 pub use key::*;
+use message_log::MessageLog;
 pub use messages::*;
-pub use termable::Termable;
+use recording::Recording;
+pub use termable::{CursorShape, Termable};
 use terminal::DefaultTerminal;
 
 pub extern crate crossterm;
 
-use std::{any::Any, fmt::Display};
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use tokio::sync::{
     mpsc::{self, Sender},
@@ -37,19 +56,96 @@ use tokio::sync::{
 pub use crokey::*;
 pub use crossterm::{cursor, event::*, style::*};
 
-use futures::{future::FutureExt, StreamExt};
+use futures::{future::FutureExt, stream::FuturesOrdered, StreamExt};
 
 /// Msg contain data from the result of a IO operation. Msgs trigger the update
 /// function and, henceforth, the UI.
 pub type Msg = Box<dyn Any + Send>;
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
 /// Input provided to [`Model::init`].
 ///
 /// This is typically used to initialize layout based on the initial terminal size.
+/// Create one with [`InitInput::new`]; two inputs are equal when their `size` is.
 pub struct InitInput {
     /// Initial terminal size `(width, height)` in cells.
     pub size: (u16, u16),
+    /// Extensions passed to [`Program::new`].
+    ///
+    /// Widgets look up configuration here during `init`, such as a
+    /// [`KeyBindings`] table that overrides their default keys.
+    pub extensions: Extensions,
+    /// Current terminal size, kept up to date by [`Program`].
+    ///
+    /// Unlike `size`, this keeps changing after `init`. Widgets can keep a clone and read
+    /// it in `view` instead of tracking [`ResizeEvent`]s themselves.
+    pub terminal_size: TerminalSize,
+}
+
+impl InitInput {
+    /// Create an input for a terminal of `size` with no extensions.
+    pub fn new(size: (u16, u16)) -> Self {
+        Self {
+            size,
+            extensions: Extensions::default(),
+            terminal_size: TerminalSize::new(size),
+        }
+    }
+
+    /// Set the initial size, e.g. to init a child with the area it is given.
+    pub fn with_size(self, size: (u16, u16)) -> Self {
+        Self { size, ..self }
+    }
+
+    /// Set the extensions widgets read their configuration from.
+    pub fn with_extensions(self, extensions: Extensions) -> Self {
+        Self { extensions, ..self }
+    }
+
+    /// Set the shared handle to the current terminal size.
+    pub fn with_terminal_size(self, terminal_size: TerminalSize) -> Self {
+        Self {
+            terminal_size,
+            ..self
+        }
+    }
+}
+
+impl PartialEq for InitInput {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+    }
+}
+
+impl Eq for InitInput {}
+
+/// A shared handle to the terminal size `(width, height)` in cells.
+///
+/// [`Program`] updates it before delivering a [`ResizeEvent`] to the model, so every
+/// clone sees the new size even if the event is never forwarded to a nested widget.
+#[derive(Debug, Default, Clone)]
+pub struct TerminalSize(Arc<AtomicU32>);
+
+impl TerminalSize {
+    /// Create a handle holding `size`.
+    pub fn new(size: (u16, u16)) -> Self {
+        let handle = Self::default();
+        handle.set(size);
+        handle
+    }
+
+    /// Return the current size.
+    pub fn get(&self) -> (u16, u16) {
+        let packed = self.0.load(Ordering::Relaxed);
+        ((packed >> 16) as u16, packed as u16)
+    }
+
+    /// Update the size seen by every clone.
+    pub fn set(&self, size: (u16, u16)) {
+        let packed = ((size.0 as u32) << 16) | size.1 as u32;
+        self.0.store(packed, Ordering::Relaxed);
+    }
 }
 
 /// Model contains the program's state as well as its core functions.
@@ -181,20 +277,37 @@ macro_rules! r#async {
     };
 }
 
+/// Size used when the terminal can't report its size, see [`Program::with_default_size`].
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
 /// Program is a terminal user interface.
 pub struct Program<M> {
     /// tea model
     model: M,
     /// Extensions
     extensions: Extensions,
-    /// window size
+    /// window size, `(0, 0)` until known
     size: (u16, u16),
+    /// window size used when the terminal reports an unusable size
+    default_size: (u16, u16),
+    /// window size shared with the model through [`InitInput::terminal_size`]
+    terminal_size: TerminalSize,
     /// if alt screen enabled, set `true`
     alt_screen: bool,
+    /// if the first frame should clear the lines it covers, set `true`
+    clear_on_start: bool,
     /// terminal
     term: Box<dyn Termable>,
     /// optional external input channel (for tests/adapters)
     input_rx: Option<mpsc::Receiver<Msg>>,
+    /// optional file that received messages are logged to
+    message_log: Option<PathBuf>,
+    /// names of user message types written to the message log
+    message_types: HashMap<TypeId, &'static str>,
+    /// optional file that rendered frames are recorded to
+    recording: Option<PathBuf>,
+    /// send an `IdleMsg` after this long without key or mouse input
+    idle_timeout: Option<Duration>,
 }
 
 /// batchMsg is the internal message used to perform a bunch of commands. You
@@ -203,11 +316,112 @@ pub type BatchMsg = Vec<Cmd>;
 
 /// A built in command that combines multiple commands together.
 ///
-/// These commands are executed in parallel, just like normal.
+/// These commands are executed in parallel, just like normal. Messages reach
+/// [`Model::update`] in the order the commands finish, which is not necessarily the
+/// order they were declared in: async commands complete whenever they are done, and
+/// messages from other commands may arrive in between. Use [`batch_ordered`] when
+/// the order matters.
 pub fn batch(msgs: BatchMsg) -> Cmd {
     Cmd::sync(Box::new(|| Box::new(msgs)))
 }
 
+/// Combine any number of commands into the single optional command [`Model::update`]
+/// returns.
+///
+/// Returns `None` when there are no commands, the command itself when there is one,
+/// and a [`batch`] otherwise, so callers never send an empty batch.
+///
+/// # Example
+///
+/// ```
+/// use matcha::{cmds, quit, Cmd};
+///
+/// let child_cmd: Option<Cmd> = None;
+/// assert!(cmds(child_cmd).is_none());
+/// assert!(cmds([Cmd::sync(Box::new(quit)), Cmd::sync(Box::new(quit))]).is_some());
+/// ```
+pub fn cmds(cmds: impl IntoIterator<Item = Cmd>) -> Option<Cmd> {
+    let mut cmds: BatchMsg = cmds.into_iter().collect();
+    match cmds.len() {
+        0 => None,
+        1 => cmds.pop(),
+        _ => Some(batch(cmds)),
+    }
+}
+
+/// orderedBatchMsg is the internal message used to perform a bunch of commands
+/// whose messages are delivered in order. You can send it with [`batch_ordered`].
+pub struct OrderedBatchMsg(pub Vec<Cmd>);
+
+/// A built in command that runs commands in parallel but delivers their messages in
+/// the order the commands were declared.
+///
+/// Messages that finish early are buffered until every message before them has been
+/// delivered, so a slow first command holds back the rest. Unlike [`sequence`], the
+/// next command does not wait for the previous message to be processed.
+///
+/// An async command whose [`Model::execute`] returns another async command has no
+/// message of its own; that command is run normally and does not hold back the batch.
+pub fn batch_ordered(cmds: Vec<Cmd>) -> Cmd {
+    Cmd::sync(Box::new(|| Box::new(OrderedBatchMsg(cmds))))
+}
+
+/// sequenceMsg is the internal message used to perform a bunch of commands one
+/// after another. You can send a sequenceMsg with Sequence.
+pub struct SequenceMsg(pub Vec<Cmd>);
+
+/// A built in command that runs commands one at a time, in order.
+///
+/// Unlike [`batch`], which hands all commands to the runtime at once, the next
+/// command of a sequence is only started after the message produced by the
+/// previous one has been processed by [`Model::update`]. This is useful for
+/// sequential animations or setup steps that depend on each other.
+///
+/// [`Cmd::Async`] commands are handed to [`Model::execute`] and do not report
+/// back to the sequence, so the sequence moves on without waiting for them.
+pub fn sequence(cmds: Vec<Cmd>) -> Cmd {
+    Cmd::sync(Box::new(|| Box::new(SequenceMsg(cmds))))
+}
+
+/// Wraps the message produced by a sequence step so the runtime can start the
+/// next step once it has been processed.
+struct SequenceStepMsg {
+    id: usize,
+    msg: Msg,
+}
+
+/// A built in command that suspends the program and runs an external process.
+///
+/// While the process runs, raw mode and the alternate screen are released and the
+/// process inherits stdin/stdout/stderr, so interactive programs such as `$EDITOR`
+/// work as expected. Once it exits, the terminal state is restored, the view is
+/// fully repainted and the message returned by `then` is sent to the update loop.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut cmd = std::process::Command::new("vim");
+/// cmd.arg("notes.txt");
+/// exec_process(cmd, |status| Box::new(EditorFinished(status.success())))
+/// ```
+pub fn exec_process<F>(cmd: std::process::Command, then: F) -> Cmd
+where
+    F: FnOnce(std::process::ExitStatus) -> Msg + Send + 'static,
+{
+    Cmd::sync(Box::new(move || {
+        Box::new(ExecProcessMsg {
+            cmd,
+            then: Box::new(then),
+        })
+    }))
+}
+
+/// The internal message used to run an external process, sent by [`exec_process`].
+struct ExecProcessMsg {
+    cmd: std::process::Command,
+    then: Box<dyn FnOnce(std::process::ExitStatus) -> Msg + Send>,
+}
+
 /// EnterAltScreen is a special command that tells the Bubble Tea program to
 /// enter the alternate screen buffer.
 ///
@@ -218,6 +432,20 @@ pub fn enter_alt_screen() -> Msg {
     Box::new(EnterAltScreenMsg)
 }
 
+/// SetWindowTitle is a special command that sets the terminal window title.
+///
+/// The title that was shown before the first change is restored when the program exits.
+pub fn set_window_title(title: impl Into<String>) -> Msg {
+    Box::new(SetWindowTitleMsg(title.into()))
+}
+
+/// SetCursorShape is a special command that sets the shape of the hardware cursor.
+///
+/// The user's default shape is restored when the program exits.
+pub fn set_cursor_shape(shape: CursorShape) -> Msg {
+    Box::new(SetCursorShapeMsg(shape))
+}
+
 /// Create a command that sleeps for `d` and then emits the message returned by `f`.
 ///
 /// This is a small helper for building timer-based behavior.
@@ -231,6 +459,25 @@ where
     }))
 }
 
+/// Create a command that emits the message returned by `f` after `d`, without blocking.
+///
+/// Unlike [`tick`], which sleeps on the thread running commands, the wait happens on
+/// a timer and other commands keep running meanwhile. Prefer this for timeouts that
+/// are scheduled often, such as one per keypress. Inside a [`sequence`] the next
+/// command waits for the delayed message.
+pub fn delay<F>(d: std::time::Duration, f: F) -> Cmd
+where
+    F: FnOnce() -> Msg + Send + 'static,
+{
+    Cmd::sync(Box::new(move || {
+        Box::new(DelayMsg(d, Cmd::sync(Box::new(f))))
+    }))
+}
+
+/// delayMsg is the internal message used to run a command once a duration has
+/// passed. You can send it with [`delay`].
+pub struct DelayMsg(pub std::time::Duration, pub Cmd);
+
 /// A marker message type commonly used with [`tick`].
 pub struct TickMsg;
 
@@ -243,19 +490,34 @@ pub struct EnterAltScreenMsg;
 /// alternate screen buffer. You can send a exitAltScreenMsg with ExitAltScreen.
 pub struct ExitAltScreenMsg;
 
+/// SetWindowTitleMsg in an internal message signals that the program should set
+/// the terminal window title. You can send a SetWindowTitleMsg with SetWindowTitle.
+pub struct SetWindowTitleMsg(pub String);
+
+/// SetCursorShapeMsg in an internal message signals that the program should set
+/// the hardware cursor shape. You can send a SetCursorShapeMsg with SetCursorShape.
+pub struct SetCursorShapeMsg(pub CursorShape);
+
 /// NewProgram creates a new Program.
 impl<M: Model> Program<M> {
     /// Create a new program using the default terminal backend.
     pub fn new(model: M, extensions: Extensions) -> Self {
         let term = DefaultTerminal;
-        let (w, h) = term.size().unwrap();
+        let (w, h) = term.size().unwrap_or((0, 0));
         Self {
             model,
             extensions,
             size: (w, h),
+            default_size: DEFAULT_SIZE,
+            terminal_size: TerminalSize::new((w, h)),
             alt_screen: false,
+            clear_on_start: false,
             term: Box::new(term),
             input_rx: None,
+            message_log: None,
+            message_types: HashMap::new(),
+            recording: None,
+            idle_timeout: None,
         }
     }
 
@@ -263,14 +525,21 @@ impl<M: Model> Program<M> {
     ///
     /// This is useful for testing or integrating with non-standard terminals.
     pub fn new_with_terminal(model: M, extensions: Extensions, term: Box<dyn Termable>) -> Self {
-        let (w, h) = term.size().unwrap();
+        let (w, h) = term.size().unwrap_or((0, 0));
         Self {
             model,
             extensions,
             size: (w, h),
+            default_size: DEFAULT_SIZE,
+            terminal_size: TerminalSize::new((w, h)),
             alt_screen: false,
+            clear_on_start: false,
             term,
             input_rx: None,
+            message_log: None,
+            message_types: HashMap::new(),
+            recording: None,
+            idle_timeout: None,
         }
     }
 
@@ -282,6 +551,35 @@ impl<M: Model> Program<M> {
         self
     }
 
+    /// Log every received message to the file at `path`.
+    ///
+    /// Each message is written as a line containing a sequence number and its type
+    /// name before it is dispatched to [`Model::update`]. Unlike logging to stdout,
+    /// this does not corrupt the rendered view.
+    pub fn with_message_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.message_log = Some(path.into());
+        self
+    }
+
+    /// Register `T` so the message log writes its type name.
+    ///
+    /// Messages are boxed as `dyn Any`, which only keeps their `TypeId`, so messages of
+    /// types that are neither registered nor defined by `matcha` are logged by that id.
+    pub fn with_message_type<T: Any>(mut self) -> Self {
+        self.message_types
+            .insert(TypeId::of::<T>(), type_name::<T>());
+        self
+    }
+
+    /// Record every rendered frame to the file at `path`.
+    ///
+    /// The file uses the asciicast v2 format, so a session can be replayed with
+    /// `asciinema play` and attached to bug reports.
+    pub fn with_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.recording = Some(path.into());
+        self
+    }
+
     /// Enable alternate screen buffer from the start.
     ///
     /// This is the recommended mode for full-screen TUIs, and makes resize redraw far more stable.
@@ -290,6 +588,37 @@ impl<M: Model> Program<M> {
         self
     }
 
+    /// Clear the lines covered by the first frame before rendering it.
+    ///
+    /// Without the alternate screen, the first frame is printed over whatever is on
+    /// the current lines (e.g. leftover shell output). This option clears that region
+    /// first. It has no effect together with [`Program::with_alt_screen`], which
+    /// always starts from a cleared screen.
+    pub fn with_clear_on_start(mut self) -> Self {
+        self.clear_on_start = true;
+        self
+    }
+
+    /// Send an [`IdleMsg`] once no key or mouse input has arrived for `timeout`.
+    ///
+    /// The timer restarts on every [`KeyEvent`] and [`MouseEvent`]. After an
+    /// [`IdleMsg`] has been sent, the next one is only sent after further input followed
+    /// by another `timeout` of silence. This is useful for auto-logout or screensavers.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the size used when the terminal can't report its size.
+    ///
+    /// Some terminals (e.g. in CI) report `(0, 0)` or fail to report a size at all,
+    /// which would make every widget compute zero columns. In that case the program
+    /// starts with this size instead. Defaults to 80x24.
+    pub fn with_default_size(mut self, width: u16, height: u16) -> Self {
+        self.default_size = (width, height);
+        self
+    }
+
     /// Start the event loop and run until a quit message is received.
     pub async fn start(self) -> anyhow::Result<()> {
         self.inner_start().await?;
@@ -298,7 +627,11 @@ impl<M: Model> Program<M> {
 
     async fn init(self, cmd_tx: Sender<Cmd>) -> Self {
         // Initialize the program.
-        let inited = self.model.init(&InitInput { size: self.size });
+        let inited = self.model.init(
+            &InitInput::new(self.size)
+                .with_extensions(self.extensions.clone())
+                .with_terminal_size(self.terminal_size.clone()),
+        );
         if let Some(cmd) = inited.1 {
             cmd_tx.send(cmd).await.unwrap();
         }
@@ -310,12 +643,33 @@ impl<M: Model> Program<M> {
 
     /// StartReturningModel initializes the program. Returns the final model.
     async fn inner_start(mut self) -> anyhow::Result<()> {
+        if self.size.0 == 0 || self.size.1 == 0 {
+            self.size = self.default_size;
+            self.terminal_size.set(self.size);
+        }
+
         // mpsc for message
         let (msg_tx, msg_rx) = mpsc::channel::<Msg>(100);
 
         // mpsc for command
         let (cmd_tx, cmd_rx) = mpsc::channel::<Cmd>(100);
 
+        let mut message_log = match self.message_log.take() {
+            Some(path) => Some(MessageLog::create(
+                &path,
+                std::mem::take(&mut self.message_types),
+            )?),
+            None => None,
+        };
+        let mut recording = match self.recording.take() {
+            Some(path) => Some(Recording::create(&path, self.size)?),
+            None => None,
+        };
+
+        if self.extensions.get::<color::ColorCapability>().is_none() {
+            self.extensions.insert(color::ColorCapability::detect());
+        }
+
         // Initialize the program.
         self = self.init(cmd_tx.clone()).await;
 
@@ -323,7 +677,10 @@ impl<M: Model> Program<M> {
 
         let event_tx = msg_tx.clone();
 
-        let input_handle = if let Some(mut input_rx) = self.input_rx.take() {
+        // Terminal events are read from stdin, which must be released while an external
+        // process owns the terminal (see `exec_process`).
+        let uses_terminal_input = self.input_rx.is_none();
+        let mut input_handle = if let Some(mut input_rx) = self.input_rx.take() {
             tokio::spawn(async move {
                 loop {
                     tokio::select! {
@@ -342,39 +699,20 @@ impl<M: Model> Program<M> {
                 }
             })
         } else {
-            let mut reader = EventStream::new();
-            tokio::spawn(async move {
-                loop {
-                    let event = reader.next().fuse();
-
-                    #[cfg(feature = "tracing")]
-                    tracing::trace!("event {:?} recieved", &event);
+            spawn_terminal_reader(event_tx)
+        };
 
-                    tokio::select! {
-                        maybe_event = event => {
-                            let res = match maybe_event {
-                                Some(Ok(Event::Key(event))) => event_tx.send(Box::new(event)).await,
-                                Some(Ok(Event::Mouse(event))) => event_tx.send(Box::new(event)).await,
-                                Some(Ok(Event::Resize(x, y))) => event_tx.send(Box::new(ResizeEvent(x, y))).await,
-                                _ => Ok(()),
-                            };
-                            if res.is_err() {
-                                #[cfg(feature = "tracing")]
-                                tracing::error!("event {:?} recieved", res);
-                                return;
-                            }
-                        },
-                        _ = (&mut shutdown_rx) => {
-                            // shutdown loop if oneshot emitted.
-                            return;
-                        }
-                    }
-                }
-            })
+        let (activity, idle_handle) = match self.idle_timeout {
+            Some(timeout) => {
+                let (activity, handle) = spawn_idle_timer(timeout, msg_tx.clone());
+                (Some(activity), Some(handle))
+            }
+            None => (None, None),
         };
 
         // clone sender for executor
         let exec_tx = msg_tx.clone();
+        let batch_ext = self.extensions.clone();
         let re_cmd_tx = cmd_tx.clone();
 
         let message_handle = tokio::spawn(async move {
@@ -419,28 +757,61 @@ impl<M: Model> Program<M> {
         self.term.hide_cursor()?;
         self.term.enable_raw_mode()?;
         let used_alt_screen = self.alt_screen;
+        let mut pushed_window_title = false;
+        let mut changed_cursor_shape = false;
         if used_alt_screen {
             self.term.enter_alt_screen()?;
             self.term.clear_all()?;
         }
         let run_result: anyhow::Result<()> = async {
             let mut prev_view = formatter::format(self.model.view(), self.size);
+            if self.clear_on_start && !used_alt_screen {
+                Self::clear_lines(self.term.as_ref(), prev_view.matches("\r\n").count() + 1)?;
+            }
             self.term.print(&prev_view)?;
+            if let Some(recording) = recording.as_mut() {
+                recording.frame(&prev_view)?;
+            }
 
             // main loop
             let mut rx = msg_rx;
+            let mut sequences: HashMap<usize, VecDeque<Cmd>> = HashMap::new();
+            let mut next_sequence_id = 0;
+            let mut finished_step = None;
             loop {
+                // The previous message belonged to a sequence and has been processed now.
+                if let Some(id) = finished_step.take() {
+                    Self::advance_sequence(&mut sequences, id, &cmd_tx).await;
+                }
+
                 let msg = rx.recv().await.unwrap();
+                let msg = match msg.downcast::<SequenceStepMsg>() {
+                    Ok(step) => {
+                        finished_step = Some(step.id);
+                        step.msg
+                    }
+                    Err(msg) => msg,
+                };
 
                 #[cfg(feature = "tracing")]
                 let span = tracing::info_span!("handle_message");
                 #[cfg(feature = "tracing")]
                 let _guard = span.enter();
 
+                if let Some(log) = message_log.as_mut() {
+                    log.write(&msg)?;
+                }
+
                 if msg.is::<QuitMsg>() {
                     break;
                 }
 
+                if let Some(activity) = &activity {
+                    if msg.is::<KeyEvent>() || msg.is::<MouseEvent>() {
+                        activity.notify_one();
+                    }
+                }
+
                 if msg.is::<BatchMsg>() {
                     if let Ok(batch) = msg.downcast::<BatchMsg>() {
                         for cmd in batch.into_iter() {
@@ -450,10 +821,112 @@ impl<M: Model> Program<M> {
                     continue;
                 }
 
+                if msg.is::<OrderedBatchMsg>() {
+                    if let Ok(batch) = msg.downcast::<OrderedBatchMsg>() {
+                        Self::run_ordered_batch(
+                            batch_ext.clone(),
+                            batch.0,
+                            msg_tx.clone(),
+                            cmd_tx.clone(),
+                        );
+                    }
+                    continue;
+                }
+
+                if msg.is::<SequenceMsg>() {
+                    if let Ok(seq) = msg.downcast::<SequenceMsg>() {
+                        let id = next_sequence_id;
+                        next_sequence_id += 1;
+                        sequences.insert(id, seq.0.into());
+                        Self::advance_sequence(&mut sequences, id, &cmd_tx).await;
+                    }
+                    continue;
+                }
+
+                if msg.is::<DelayMsg>() {
+                    if let Ok(delay) = msg.downcast::<DelayMsg>() {
+                        let DelayMsg(duration, cmd) = *delay;
+                        // A delayed sequence step holds the sequence back until it fires.
+                        let cmd = match (finished_step.take(), cmd) {
+                            (Some(id), Cmd::Sync(SyncCmd(cmd))) => {
+                                sync!(Box::new(SequenceStepMsg { id, msg: cmd() }))
+                            }
+                            (id, cmd) => {
+                                finished_step = id;
+                                cmd
+                            }
+                        };
+                        let cmd_tx = cmd_tx.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(duration).await;
+                            let _ = cmd_tx.send(cmd).await;
+                        });
+                    }
+                    continue;
+                }
+
+                if msg.is::<ExecProcessMsg>() {
+                    if let Ok(exec) = msg.downcast::<ExecProcessMsg>() {
+                        // Stop reading stdin so the child process receives all input.
+                        if uses_terminal_input {
+                            input_handle.abort();
+                        }
+                        let status =
+                            Self::exec_process(self.term.as_ref(), self.alt_screen, exec.cmd).await;
+                        if uses_terminal_input {
+                            input_handle = spawn_terminal_reader(msg_tx.clone());
+                        }
+                        let status = status?;
+
+                        // The child may have drawn anything, so force a full repaint.
+                        Self::clear_view(self.term.as_ref(), self.alt_screen, &prev_view)?;
+                        let size = self.term.size()?;
+                        if size != self.size && size.0 > 0 && size.1 > 0 {
+                            // Resize events were not observed while the child was running.
+                            self.size = size;
+                            self.terminal_size.set(size);
+                            cmd_tx
+                                .send(sync!(Box::new(ResizeEvent(size.0, size.1))))
+                                .await
+                                .unwrap();
+                        }
+                        prev_view = formatter::format(self.model.view(), self.size);
+                        self.term.print(&prev_view)?;
+                        if let Some(recording) = recording.as_mut() {
+                            recording.frame(&prev_view)?;
+                        }
+
+                        let then = exec.then;
+                        cmd_tx.send(sync!(then(status))).await.unwrap();
+                    }
+                    continue;
+                }
+
+                if msg.is::<BellMsg>() {
+                    self.term.print("\x07")?;
+                    continue;
+                }
+
+                if let Some(SetWindowTitleMsg(title)) = msg.downcast_ref::<SetWindowTitleMsg>() {
+                    if !pushed_window_title {
+                        self.term.push_window_title()?;
+                        pushed_window_title = true;
+                    }
+                    self.term.set_window_title(title)?;
+                    continue;
+                }
+
+                if let Some(SetCursorShapeMsg(shape)) = msg.downcast_ref::<SetCursorShapeMsg>() {
+                    self.term.set_cursor_shape(*shape)?;
+                    changed_cursor_shape = true;
+                    continue;
+                }
+
                 if let Some(event) = msg.downcast_ref::<ResizeEvent>() {
                     #[cfg(feature = "tracing")]
                     tracing::trace!("resize event recieved w = {}, h = {}", event.0, event.1);
                     self.size = (event.0, event.1);
+                    self.terminal_size.set(self.size);
                 }
 
                 if msg.is::<EnterAltScreenMsg>() {
@@ -481,21 +954,12 @@ impl<M: Model> Program<M> {
                     continue;
                 }
 
-                if self.alt_screen {
-                    self.term.clear_all()?;
-                } else {
-                    self.term.move_to_column(0)?;
-                    if prev_view.matches("\r\n").count() == 0 {
-                        self.term.clear_current_line()?;
-                    } else {
-                        self.term.clear_current_line()?;
-                        for _ in 0..prev_view.matches("\r\n").count() {
-                            self.term.clear_current_line_and_move_previous()?;
-                        }
-                    }
-                }
+                Self::clear_view(self.term.as_ref(), self.alt_screen, &prev_view)?;
 
                 self.term.print(&current_view)?;
+                if let Some(recording) = recording.as_mut() {
+                    recording.frame(&current_view)?;
+                }
                 prev_view = current_view;
             }
             Ok(())
@@ -508,15 +972,143 @@ impl<M: Model> Program<M> {
         message_handle.abort();
         let _ = shutdown_tx.send(true);
         input_handle.abort();
+        if let Some(handle) = idle_handle {
+            handle.abort();
+        }
 
-        let cleanup_result = Self::cleanup_terminal(self.term.as_ref(), used_alt_screen);
+        // A closed output (e.g. `app | head`) is a normal way for the program to end.
+        let run_result = run_result.or_else(|e| if is_broken_pipe(&e) { Ok(()) } else { Err(e) });
+        let cleanup_result = Self::cleanup_terminal(
+            self.term.as_ref(),
+            used_alt_screen,
+            pushed_window_title,
+            changed_cursor_shape,
+        );
         run_result.and(cleanup_result)
     }
 
-    fn cleanup_terminal(term: &dyn Termable, used_alt_screen: bool) -> anyhow::Result<()> {
+    /// Hand the terminal over to `cmd`, wait for it to exit and take the terminal back.
+    async fn exec_process(
+        term: &dyn Termable,
+        alt_screen: bool,
+        mut cmd: std::process::Command,
+    ) -> anyhow::Result<std::process::ExitStatus> {
+        if alt_screen {
+            term.leave_alt_screen()?;
+        }
+        term.show_cursor()?;
+        term.disable_raw_mode()?;
+
+        let status = tokio::task::spawn_blocking(move || cmd.status()).await?;
+
+        term.enable_raw_mode()?;
+        term.hide_cursor()?;
+        if alt_screen {
+            term.enter_alt_screen()?;
+        }
+        Ok(status?)
+    }
+
+    /// Send the next command of sequence `id`, or forget the sequence when it is done.
+    async fn advance_sequence(
+        sequences: &mut HashMap<usize, VecDeque<Cmd>>,
+        id: usize,
+        cmd_tx: &Sender<Cmd>,
+    ) {
+        while let Some(cmd) = sequences.get_mut(&id).and_then(|cmds| cmds.pop_front()) {
+            match cmd {
+                Cmd::Sync(SyncCmd(cmd)) => {
+                    let step = sync!(Box::new(SequenceStepMsg { id, msg: cmd() }));
+                    cmd_tx.send(step).await.unwrap();
+                    return;
+                }
+                // Async commands never report back, so don't wait for them.
+                cmd => cmd_tx.send(cmd).await.unwrap(),
+            }
+        }
+        sequences.remove(&id);
+    }
+
+    /// Run `cmds` concurrently and send their messages to `msg_tx` in declaration order.
+    fn run_ordered_batch(
+        ext: Extensions,
+        cmds: Vec<Cmd>,
+        msg_tx: Sender<Msg>,
+        cmd_tx: Sender<Cmd>,
+    ) {
+        let mut pending: FuturesOrdered<_> = cmds
+            .into_iter()
+            .map(|cmd| {
+                let ext = ext.clone();
+                let cmd_tx = cmd_tx.clone();
+                async move {
+                    let cmd = match cmd {
+                        Cmd::Async(cmd) => M::execute(ext, cmd).await?,
+                        cmd => cmd,
+                    };
+                    match cmd {
+                        // Sync commands may block (e.g. `tick`), so keep them off the runtime.
+                        Cmd::Sync(SyncCmd(cmd)) => tokio::task::spawn_blocking(cmd).await.ok(),
+                        cmd => {
+                            let _ = cmd_tx.send(cmd).await;
+                            None
+                        }
+                    }
+                }
+            })
+            .collect();
+        tokio::spawn(async move {
+            while let Some(msg) = pending.next().await {
+                if let Some(msg) = msg {
+                    if msg_tx.send(msg).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Clear the previously printed `view` so the next frame is drawn in its place.
+    fn clear_view(term: &dyn Termable, alt_screen: bool, view: &str) -> std::io::Result<()> {
+        if alt_screen {
+            return term.clear_all();
+        }
+        term.move_to_column(0)?;
+        term.clear_current_line()?;
+        for _ in 0..view.matches("\r\n").count() {
+            term.clear_current_line_and_move_previous()?;
+        }
+        Ok(())
+    }
+
+    /// Clear `lines` lines starting at the current line and move back to its first column.
+    fn clear_lines(term: &dyn Termable, lines: usize) -> std::io::Result<()> {
+        let below = lines.saturating_sub(1);
+        term.move_to_column(0)?;
+        // Make room first so the region is still on screen if the terminal scrolls.
+        if below > 0 {
+            term.print(&"\r\n".repeat(below))?;
+        }
+        term.clear_current_line()?;
+        for _ in 0..below {
+            term.clear_current_line_and_move_previous()?;
+        }
+        Ok(())
+    }
+
+    fn cleanup_terminal(
+        term: &dyn Termable,
+        used_alt_screen: bool,
+        pushed_window_title: bool,
+        changed_cursor_shape: bool,
+    ) -> anyhow::Result<()> {
         let mut first_error = None;
         let mut record = |result: Result<(), std::io::Error>, label: &str| {
             if let Err(error) = result {
+                // Nothing can be restored through a closed output.
+                if error.kind() == std::io::ErrorKind::BrokenPipe {
+                    return;
+                }
                 if first_error.is_none() {
                     first_error = Some(anyhow::anyhow!("failed to {}: {}", label, error));
                 }
@@ -530,6 +1122,15 @@ impl<M: Model> Program<M> {
         if used_alt_screen {
             record(term.leave_alt_screen(), "leave alternate screen");
         }
+        if pushed_window_title {
+            record(term.pop_window_title(), "restore window title");
+        }
+        if changed_cursor_shape {
+            record(
+                term.set_cursor_shape(CursorShape::Default),
+                "reset cursor shape",
+            );
+        }
 
         if let Some(error) = first_error {
             return Err(error);
@@ -538,6 +1139,64 @@ impl<M: Model> Program<M> {
     }
 }
 
+/// Whether `error` was caused by writing to a closed output.
+fn is_broken_pipe(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe)
+}
+
+/// Forward crossterm events from the terminal into the message channel.
+///
+/// The task runs until it is aborted or the channel is closed.
+fn spawn_terminal_reader(event_tx: Sender<Msg>) -> tokio::task::JoinHandle<()> {
+    let mut reader = EventStream::new();
+    tokio::spawn(async move {
+        loop {
+            let event = reader.next().fuse().await;
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!("event {:?} recieved", &event);
+
+            let res = match event {
+                Some(Ok(Event::Key(event))) => event_tx.send(Box::new(event)).await,
+                Some(Ok(Event::Mouse(event))) => event_tx.send(Box::new(event)).await,
+                Some(Ok(Event::Resize(x, y))) => event_tx.send(Box::new(ResizeEvent(x, y))).await,
+                _ => Ok(()),
+            };
+            if res.is_err() {
+                #[cfg(feature = "tracing")]
+                tracing::error!("event {:?} recieved", res);
+                return;
+            }
+        }
+    })
+}
+
+/// Send an [`IdleMsg`] to `msg_tx` once `activity` hasn't been notified for `timeout`.
+fn spawn_idle_timer(
+    timeout: Duration,
+    msg_tx: Sender<Msg>,
+) -> (Arc<tokio::sync::Notify>, tokio::task::JoinHandle<()>) {
+    let activity = Arc::new(tokio::sync::Notify::new());
+    let notified = activity.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = notified.notified() => continue,
+                _ = tokio::time::sleep(timeout) => {
+                    if msg_tx.send(Box::new(IdleMsg)).await.is_err() {
+                        return;
+                    }
+                    // Stay quiet until there is input again.
+                    notified.notified().await;
+                }
+            }
+        }
+    });
+    (activity, handle)
+}
+
 /// Event representing a terminal resize (x, y).
 /// Boxed as a message so it can be sent to the application.
 pub struct ResizeEvent(pub u16, pub u16);
@@ -551,7 +1210,9 @@ mod tests {
     use tokio::sync::mpsc;
 
     use crate::{
-        quit, Cmd, Extensions, KeyCode, KeyEvent, KeyModifiers, Model, Msg, Program, Termable,
+        bell, color::ColorCapability, quit, set_cursor_shape, set_window_title, sync,
+        BufferTerminal, Cmd, CursorShape, Extensions, InitInput, KeyCode, KeyEvent, KeyModifiers,
+        Model, Msg, Program, ResizeEvent, Termable, TerminalSize,
     };
 
     struct FakeTerminal {
@@ -673,6 +1334,915 @@ mod tests {
         assert!(!out.is_empty(), "program should render at least once");
     }
 
+    #[tokio::test]
+    async fn buffer_terminal_shows_final_screen() {
+        let term = BufferTerminal::new(10, 3);
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        for ch in ['a', 'b', 'q'] {
+            tx.send(Box::new(KeyEvent::new(
+                KeyCode::Char(ch),
+                KeyModifiers::NONE,
+            )))
+            .await
+            .unwrap();
+        }
+        drop(tx);
+
+        Program::new_with_terminal(
+            TestModel {
+                seen: String::new(),
+            },
+            Extensions::default(),
+            Box::new(term.clone()),
+        )
+        .with_input_receiver(rx)
+        .start()
+        .await
+        .unwrap();
+
+        assert_eq!(term.contents(), "abq\n\n");
+    }
+
+    struct SizeProbe(&'static str, TerminalSize);
+
+    impl Model for SizeProbe {
+        fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+            (Self(self.0, input.terminal_size.clone()), None)
+        }
+
+        fn view(&self) -> impl Display {
+            let (w, h) = self.1.get();
+            format!("{}:{}x{}", self.0, w, h)
+        }
+    }
+
+    struct ProbePair(SizeProbe, SizeProbe);
+
+    impl Model for ProbePair {
+        fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+            let (a, _) = self.0.init(input);
+            let (b, _) = self.1.init(input);
+            (Self(a, b), None)
+        }
+
+        // Resize events are deliberately not forwarded to the children.
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if msg.is::<KeyEvent>() {
+                return (self, Some(Cmd::sync(Box::new(quit))));
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            format!("{}\n{}", self.0.view(), self.1.view())
+        }
+    }
+
+    #[tokio::test]
+    async fn terminal_size_reaches_nested_widgets_without_forwarding() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        tx.send(Box::new(ResizeEvent(100, 30))).await.unwrap();
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        drop(tx);
+
+        let probes = ProbePair(
+            SizeProbe("a", TerminalSize::default()),
+            SizeProbe("b", TerminalSize::default()),
+        );
+        Program::new_with_terminal(probes, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await
+            .unwrap();
+
+        let printed = printed.lock().unwrap();
+        assert!(printed[0].contains("a:80x24"));
+        let last = printed.last().unwrap();
+        assert!(last.contains("a:100x30") && last.contains("b:100x30"));
+    }
+
+    struct CapabilityProbe(Option<ColorCapability>);
+
+    impl Model for CapabilityProbe {
+        fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+            let capability = input.extensions.get::<ColorCapability>().copied();
+            (Self(capability), Some(Cmd::sync(Box::new(quit))))
+        }
+
+        fn view(&self) -> impl Display {
+            format!("{:?}", self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn color_capability_is_available_in_init() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+        let mut extensions = Extensions::default();
+        extensions.insert(ColorCapability { truecolor: false });
+
+        Program::new_with_terminal(CapabilityProbe(None), extensions, Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await
+            .unwrap();
+
+        let printed = printed.lock().unwrap();
+        assert!(printed[0].starts_with("Some(ColorCapability { truecolor: false })"));
+    }
+
+    #[tokio::test]
+    async fn message_log_names_registered_user_msgs() {
+        struct Refresh;
+
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        let path =
+            std::env::temp_dir().join(format!("matcha-msg-names-{}.log", std::process::id()));
+
+        tx.send(Box::new(Refresh)).await.unwrap();
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        drop(tx);
+
+        Program::new_with_terminal(
+            TestModel {
+                seen: String::new(),
+            },
+            Extensions::default(),
+            Box::new(term),
+        )
+        .with_input_receiver(rx)
+        .with_message_log(&path)
+        .with_message_type::<Refresh>()
+        .start()
+        .await
+        .unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let first = log.lines().next().unwrap();
+        assert!(
+            first.starts_with("1\t") && first.ends_with("::Refresh"),
+            "{first}"
+        );
+    }
+
+    #[tokio::test]
+    async fn message_log_records_each_received_msg() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        let path = std::env::temp_dir().join(format!("matcha-msg-log-{}.log", std::process::id()));
+
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        drop(tx);
+
+        let p = Program::new_with_terminal(
+            TestModel {
+                seen: String::new(),
+            },
+            Extensions::default(),
+            Box::new(term),
+        )
+        .with_input_receiver(rx)
+        .with_message_log(&path);
+        p.start().await.unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<&str> = log.lines().collect();
+        assert_eq!(
+            lines.len(),
+            2,
+            "key event and quit message should be logged"
+        );
+        assert!(lines[0].starts_with("1\t") && lines[0].ends_with("KeyEvent"));
+        assert!(lines[1].starts_with("2\t") && lines[1].ends_with("QuitMsg"));
+    }
+
+    #[tokio::test]
+    async fn recording_writes_one_entry_per_rendered_frame() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        let path = std::env::temp_dir().join(format!("matcha-rec-{}.cast", std::process::id()));
+
+        // Every key changes the view, so each update renders a frame.
+        for ch in ['a', 'b', 'c', 'q'] {
+            tx.send(Box::new(KeyEvent::new(
+                KeyCode::Char(ch),
+                KeyModifiers::NONE,
+            )))
+            .await
+            .unwrap();
+        }
+        drop(tx);
+
+        Program::new_with_terminal(
+            TestModel {
+                seen: String::new(),
+            },
+            Extensions::default(),
+            Box::new(term),
+        )
+        .with_input_receiver(rx)
+        .with_recording(&path)
+        .start()
+        .await
+        .unwrap();
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<serde_json::Value> = cast
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines[0]["version"], 2);
+        // The header, the initial frame and one frame per update.
+        assert_eq!(lines.len(), 1 + 1 + 4);
+        assert_eq!(lines[1][1], "o");
+        assert!(lines[5][2].as_str().unwrap().trim_end().ends_with("abcq"));
+    }
+
+    #[derive(Default)]
+    struct TestTerminal {
+        calls: Arc<Mutex<Vec<String>>>,
+        print_error: Option<std::io::ErrorKind>,
+        size: Option<(u16, u16)>,
+    }
+
+    impl TestTerminal {
+        fn record(&self, call: impl Into<String>) -> Result<(), std::io::Error> {
+            self.calls.lock().unwrap().push(call.into());
+            Ok(())
+        }
+    }
+
+    impl Termable for TestTerminal {
+        fn size(&self) -> Result<(u16, u16), std::io::Error> {
+            Ok(self.size.unwrap_or((10, 5)))
+        }
+        fn hide_cursor(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn show_cursor(&self) -> Result<(), std::io::Error> {
+            self.record("show_cursor")
+        }
+        fn enable_raw_mode(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn disable_raw_mode(&self) -> Result<(), std::io::Error> {
+            self.record("disable_raw_mode")
+        }
+        fn print(&self, v: &str) -> Result<(), std::io::Error> {
+            self.record(format!("print:{v}"))?;
+            match self.print_error {
+                Some(kind) => Err(kind.into()),
+                None => Ok(()),
+            }
+        }
+        fn enter_alt_screen(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn leave_alt_screen(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn enable_mouse_capture(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn disable_mouse_capture(&self) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn move_to_column(&self, y: u16) -> Result<(), std::io::Error> {
+            self.record(format!("move_to_column:{y}"))
+        }
+        fn set_window_title(&self, title: &str) -> Result<(), std::io::Error> {
+            self.record(format!("set_window_title:{title}"))
+        }
+        fn push_window_title(&self) -> Result<(), std::io::Error> {
+            self.record("push_window_title")
+        }
+        fn pop_window_title(&self) -> Result<(), std::io::Error> {
+            self.record("pop_window_title")
+        }
+        fn set_cursor_shape(&self, shape: CursorShape) -> Result<(), std::io::Error> {
+            self.record(format!("set_cursor_shape:{shape:?}"))
+        }
+        fn move_to(&self, _x: u16, _y: u16) -> Result<(), std::io::Error> {
+            Ok(())
+        }
+        fn cursor_position(&self) -> Result<(u16, u16), std::io::Error> {
+            Ok((0, 0))
+        }
+        fn clear_all(&self) -> Result<(), std::io::Error> {
+            self.record("clear_all")
+        }
+        fn clear_current_line(&self) -> Result<(), std::io::Error> {
+            self.record("clear_current_line")
+        }
+        fn clear_current_line_and_move_previous(&self) -> Result<(), std::io::Error> {
+            self.record("clear_current_line_and_move_previous")
+        }
+    }
+
+    struct ThreeLines;
+
+    impl Model for ThreeLines {
+        fn update(self, _msg: &Msg) -> (Self, Option<Cmd>) {
+            (self, Some(Cmd::sync(Box::new(quit))))
+        }
+
+        fn view(&self) -> impl Display {
+            "a\nb\nc"
+        }
+    }
+
+    async fn first_frame_calls(clear_on_start: bool) -> Vec<String> {
+        let term = TestTerminal::default();
+        let calls = term.calls.clone();
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+        drop(tx);
+
+        let mut p = Program::new_with_terminal(ThreeLines, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx);
+        if clear_on_start {
+            p = p.with_clear_on_start();
+        }
+        p.start().await.unwrap();
+
+        let calls = calls.lock().unwrap();
+        let first_frame = calls
+            .iter()
+            .position(|c| c.starts_with("print:a"))
+            .expect("first frame should be printed");
+        calls[..first_frame].to_vec()
+    }
+
+    #[tokio::test]
+    async fn clear_on_start_clears_region_before_first_frame() {
+        assert_eq!(
+            first_frame_calls(true).await,
+            vec![
+                "move_to_column:0",
+                "print:\r\n\r\n",
+                "clear_current_line",
+                "clear_current_line_and_move_previous",
+                "clear_current_line_and_move_previous",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn first_frame_is_not_cleared_by_default() {
+        assert!(first_frame_calls(false).await.is_empty());
+    }
+
+    struct InitSize((u16, u16));
+
+    impl Model for InitSize {
+        fn init(self, input: &InitInput) -> (Self, Option<Cmd>) {
+            (Self(input.size), Some(Cmd::sync(Box::new(quit))))
+        }
+
+        fn view(&self) -> impl Display {
+            format!("{}x{}", self.0 .0, self.0 .1)
+        }
+    }
+
+    #[tokio::test]
+    async fn zero_terminal_size_falls_back_to_default_size() {
+        let term = TestTerminal {
+            size: Some((0, 0)),
+            ..TestTerminal::default()
+        };
+        let calls = term.calls.clone();
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        Program::new_with_terminal(InitSize((0, 0)), Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .with_default_size(80, 24)
+            .start()
+            .await
+            .unwrap();
+
+        let calls = calls.lock().unwrap();
+        let frame = calls.iter().find_map(|c| c.strip_prefix("print:")).unwrap();
+        assert_eq!(frame, format!("{:<80}", "80x24"));
+    }
+
+    #[tokio::test]
+    async fn broken_pipe_shuts_down_cleanly() {
+        let term = TestTerminal {
+            print_error: Some(std::io::ErrorKind::BrokenPipe),
+            ..TestTerminal::default()
+        };
+        let calls = term.calls.clone();
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        let result = Program::new_with_terminal(ThreeLines, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "broken pipe should not be an error: {result:?}"
+        );
+        let calls = calls.lock().unwrap();
+        assert!(calls.contains(&"disable_raw_mode".to_string()));
+        assert!(calls.contains(&"show_cursor".to_string()));
+    }
+
+    struct BellModel;
+
+    impl Model for BellModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            let cmds = vec![Cmd::sync(Box::new(bell)), Cmd::sync(Box::new(quit))];
+            (self, Some(crate::sequence(cmds)))
+        }
+
+        fn update(self, _msg: &Msg) -> (Self, Option<Cmd>) {
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    #[tokio::test]
+    async fn bell_writes_bel_byte() {
+        let term = TestTerminal::default();
+        let calls = term.calls.clone();
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        Program::new_with_terminal(BellModel, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await
+            .unwrap();
+
+        assert!(calls.lock().unwrap().contains(&"print:\x07".to_string()));
+    }
+
+    struct TitleModel;
+
+    impl Model for TitleModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            let cmds = vec![
+                sync!(set_window_title("a.txt")),
+                sync!(set_window_title("b.txt")),
+                Cmd::sync(Box::new(quit)),
+            ];
+            (self, Some(crate::sequence(cmds)))
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    #[tokio::test]
+    async fn window_title_is_restored_on_exit() {
+        let term = TestTerminal::default();
+        let calls = term.calls.clone();
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        Program::new_with_terminal(TitleModel, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await
+            .unwrap();
+
+        let calls = calls.lock().unwrap();
+        let titles: Vec<&String> = calls
+            .iter()
+            .filter(|c| c.contains("window_title"))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "push_window_title",
+                "set_window_title:a.txt",
+                "set_window_title:b.txt",
+                "pop_window_title",
+            ]
+        );
+    }
+
+    struct CursorShapeModel;
+
+    impl Model for CursorShapeModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            let cmds = vec![
+                sync!(set_cursor_shape(CursorShape::Bar)),
+                Cmd::sync(Box::new(quit)),
+            ];
+            (self, Some(crate::sequence(cmds)))
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    #[tokio::test]
+    async fn cursor_shape_is_reset_on_exit() {
+        let term = TestTerminal::default();
+        let calls = term.calls.clone();
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        Program::new_with_terminal(CursorShapeModel, Extensions::default(), Box::new(term))
+            .with_input_receiver(rx)
+            .start()
+            .await
+            .unwrap();
+
+        let calls = calls.lock().unwrap();
+        let shapes: Vec<&String> = calls
+            .iter()
+            .filter(|c| c.contains("cursor_shape"))
+            .collect();
+        assert_eq!(
+            shapes,
+            vec!["set_cursor_shape:Bar", "set_cursor_shape:Default"]
+        );
+    }
+
+    struct SequenceModel {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct Step(usize);
+
+    impl Model for SequenceModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            let cmds = (1..=3)
+                .map(|n| {
+                    let events = self.events.clone();
+                    Cmd::sync(Box::new(move || {
+                        events.lock().unwrap().push(format!("run {n}"));
+                        Box::new(Step(n)) as Msg
+                    }))
+                })
+                .collect();
+            (self, Some(crate::sequence(cmds)))
+        }
+
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if let Some(Step(n)) = msg.downcast_ref::<Step>() {
+                self.events.lock().unwrap().push(format!("recv {n}"));
+                if *n == 3 {
+                    return (self, Some(Cmd::sync(Box::new(quit))));
+                }
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    fn run_sync(cmd: Cmd) -> Msg {
+        match cmd {
+            Cmd::Sync(crate::SyncCmd(f)) => f(),
+            Cmd::Async(_) => panic!("expected a sync command"),
+        }
+    }
+
+    #[test]
+    fn init_inputs_compare_by_size() {
+        let mut extensions = Extensions::new();
+        extensions.insert(1u8);
+        let input = InitInput::new((80, 24));
+        assert_eq!(input.terminal_size.get(), (80, 24));
+        assert_eq!(input.clone().with_extensions(extensions), input);
+        assert_ne!(input.clone().with_size((40, 24)), input);
+    }
+
+    #[test]
+    fn cmds_is_none_for_no_commands() {
+        assert!(crate::cmds(std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn cmds_returns_a_single_command_as_is() {
+        let cmd = crate::cmds([sync!(Box::new(Step(1)))]).unwrap();
+        assert!(run_sync(cmd).is::<Step>());
+    }
+
+    #[test]
+    fn cmds_batches_several_commands() {
+        let cmd = crate::cmds((1..=3).map(|n| sync!(Box::new(Step(n))))).unwrap();
+        let batch = run_sync(cmd).downcast::<crate::BatchMsg>().unwrap();
+        assert_eq!(batch.len(), 3);
+    }
+
+    struct IdleModel {
+        start: tokio::time::Instant,
+        idle_after: Arc<Mutex<Option<std::time::Duration>>>,
+    }
+
+    impl Model for IdleModel {
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if msg.is::<crate::IdleMsg>() {
+                *self.idle_after.lock().unwrap() = Some(self.start.elapsed());
+                return (self, Some(Cmd::sync(Box::new(quit))));
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    /// Run an [`IdleModel`] with a 10s idle timeout, sending a key after each delay.
+    async fn idle_after(key_delays: Vec<u64>) -> std::time::Duration {
+        let idle_after = Arc::new(Mutex::new(None));
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        tokio::spawn(async move {
+            for delay in key_delays {
+                tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+                let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+                let _ = tx.send(Box::new(key)).await;
+            }
+            // Keep the input open until the program quits.
+            tx.closed().await;
+        });
+
+        let p = Program::new_with_terminal(
+            IdleModel {
+                start: tokio::time::Instant::now(),
+                idle_after: idle_after.clone(),
+            },
+            Extensions::default(),
+            Box::new(FakeTerminal::new(Arc::new(Mutex::new(Vec::new())))),
+        )
+        .with_input_receiver(rx)
+        .with_idle_timeout(std::time::Duration::from_secs(10));
+        p.start().await.unwrap();
+
+        let elapsed = idle_after.lock().unwrap().expect("no IdleMsg was sent");
+        elapsed
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn idle_msg_is_sent_after_timeout_without_input() {
+        let elapsed = idle_after(vec![]).await;
+        assert_eq!(elapsed.as_secs(), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn input_restarts_idle_timer() {
+        // Keys at 6s and 12s, so the program is idle from 12s on.
+        let elapsed = idle_after(vec![6, 6]).await;
+        assert_eq!(elapsed.as_secs(), 22);
+    }
+
+    struct OrderedBatchModel {
+        received: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Model for OrderedBatchModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            // The first command finishes last, so an unordered batch would deliver 3, 2, 1.
+            let cmds = (1..=3)
+                .map(|n| {
+                    let delay = std::time::Duration::from_millis(60 - 20 * n as u64);
+                    crate::tick(delay, move || Box::new(Step(n)))
+                })
+                .collect();
+            (self, Some(crate::batch_ordered(cmds)))
+        }
+
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if let Some(Step(n)) = msg.downcast_ref::<Step>() {
+                self.received.lock().unwrap().push(*n);
+                if *n == 3 {
+                    return (self, Some(Cmd::sync(Box::new(quit))));
+                }
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    #[tokio::test]
+    async fn ordered_batch_delivers_messages_in_declared_order() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        let p = Program::new_with_terminal(
+            OrderedBatchModel {
+                received: received.clone(),
+            },
+            Extensions::default(),
+            Box::new(FakeTerminal::new(Arc::new(Mutex::new(Vec::new())))),
+        )
+        .with_input_receiver(rx);
+        p.start().await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn sequence_runs_next_command_after_previous_msg_is_processed() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let events = Arc::new(Mutex::new(Vec::<String>::new()));
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        let p = Program::new_with_terminal(
+            SequenceModel {
+                events: events.clone(),
+            },
+            Extensions::default(),
+            Box::new(FakeTerminal::new(printed)),
+        )
+        .with_input_receiver(rx);
+        p.start().await.unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec!["run 1", "recv 1", "run 2", "recv 2", "run 3", "recv 3"]
+        );
+    }
+
+    struct DelayModel {
+        received: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Model for DelayModel {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            let delayed = crate::sequence(vec![
+                crate::delay(std::time::Duration::from_millis(40), || Box::new(Step(1))),
+                sync!(Box::new(Step(2))),
+            ]);
+            (
+                self,
+                Some(crate::batch(vec![delayed, sync!(Box::new(Step(3)))])),
+            )
+        }
+
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if let Some(Step(n)) = msg.downcast_ref::<Step>() {
+                self.received.lock().unwrap().push(*n);
+                if *n == 2 {
+                    return (self, Some(Cmd::sync(Box::new(quit))));
+                }
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            ""
+        }
+    }
+
+    #[tokio::test]
+    async fn delay_does_not_hold_back_other_commands() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let (_tx, rx) = mpsc::channel::<Msg>(8);
+
+        let p = Program::new_with_terminal(
+            DelayModel {
+                received: received.clone(),
+            },
+            Extensions::default(),
+            Box::new(FakeTerminal::new(Arc::new(Mutex::new(Vec::new())))),
+        )
+        .with_input_receiver(rx);
+        p.start().await.unwrap();
+
+        // Step 3 isn't stuck behind the delay, and the sequence waits for it.
+        assert_eq!(*received.lock().unwrap(), vec![3, 1, 2]);
+    }
+
+    struct ExecModel {
+        status: Option<bool>,
+    }
+
+    struct ExecFinished(bool);
+
+    #[async_trait::async_trait]
+    impl Model for ExecModel {
+        fn update(mut self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if let Some(key) = msg.downcast_ref::<KeyEvent>() {
+                if key.code == KeyCode::Char('e') {
+                    let cmd = crate::exec_process(std::process::Command::new("true"), |status| {
+                        Box::new(ExecFinished(status.success()))
+                    });
+                    return (self, Some(cmd));
+                }
+            }
+            if let Some(ExecFinished(success)) = msg.downcast_ref::<ExecFinished>() {
+                self.status = Some(*success);
+                return (self, Some(Cmd::sync(Box::new(quit))));
+            }
+            (self, None)
+        }
+
+        fn view(&self) -> impl Display {
+            format!("{:?}", self.status)
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn exec_process_repaints_and_sends_follow_up_msg() {
+        let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+        let term = FakeTerminal::new(printed.clone());
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('e'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+
+        let p = Program::new_with_terminal(
+            ExecModel { status: None },
+            Extensions::default(),
+            Box::new(term),
+        )
+        .with_input_receiver(rx);
+        p.start().await.unwrap();
+        drop(tx);
+
+        let out = printed.lock().unwrap();
+        assert_eq!(
+            out.iter().filter(|v| v.starts_with("None")).count(),
+            2,
+            "view should be repainted after the process exits"
+        );
+        assert!(out.last().unwrap().starts_with("Some(true)"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn exec_process_clears_old_frame_and_repaints_at_new_size() {
+        let term = TestTerminal::default();
+        let calls = term.calls.clone();
+        let (tx, rx) = mpsc::channel::<Msg>(8);
+        tx.send(Box::new(KeyEvent::new(
+            KeyCode::Char('e'),
+            KeyModifiers::NONE,
+        )))
+        .await
+        .unwrap();
+
+        let mut p = Program::new_with_terminal(
+            ExecModel { status: None },
+            Extensions::default(),
+            Box::new(term),
+        )
+        .with_input_receiver(rx);
+        // The terminal was resized while the child process was running.
+        p.size = (6, 5);
+        p.start().await.unwrap();
+        drop(tx);
+
+        let calls = calls.lock().unwrap();
+        let resumed = calls
+            .iter()
+            .position(|c| c == "disable_raw_mode")
+            .expect("the child process should take over the terminal");
+        assert_eq!(
+            calls[resumed + 1..resumed + 4],
+            ["move_to_column:0", "clear_current_line", "print:None      ",]
+        );
+    }
+
     struct FailingCleanupTerminal {
         calls: Arc<Mutex<Vec<String>>>,
     }
@@ -762,7 +2332,7 @@ mod tests {
         let calls = Arc::new(Mutex::new(vec![]));
         let term = FailingCleanupTerminal::new(calls.clone());
 
-        let result = Program::<TestModel>::cleanup_terminal(&term, true);
+        let result = Program::<TestModel>::cleanup_terminal(&term, true, false, false);
 
         assert!(
             result.is_err(),
@@ -0,0 +1,143 @@
+use std::{
+    any::{type_name, type_name_of_val, Any, TypeId},
+    collections::HashMap,
+    fs::File,
+    io::{LineWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    BatchMsg, BellMsg, DelayMsg, EnterAltScreenMsg, ExecProcessMsg, ExitAltScreenMsg, IdleMsg,
+    KeyEvent, MouseEvent, Msg, OrderedBatchMsg, QuitMsg, ResizeEvent, SequenceMsg, SequenceStepMsg,
+    SetCursorShapeMsg, SetWindowTitleMsg, TickMsg,
+};
+
+/// Message types delivered by `matcha` itself, as `(type id, type name, identifier)`.
+///
+/// Every `*Msg` type defined in this crate must be listed here, including the internal
+/// ones, so the log never falls back to a bare `TypeId` for them.
+fn framework_types() -> Vec<(TypeId, &'static str, &'static str)> {
+    macro_rules! known {
+        ($($ty:ident),* $(,)?) => {
+            vec![$((TypeId::of::<$ty>(), type_name::<$ty>(), stringify!($ty))),*]
+        };
+    }
+    known!(
+        KeyEvent,
+        MouseEvent,
+        ResizeEvent,
+        QuitMsg,
+        BatchMsg,
+        OrderedBatchMsg,
+        SequenceMsg,
+        SequenceStepMsg,
+        ExecProcessMsg,
+        DelayMsg,
+        TickMsg,
+        EnterAltScreenMsg,
+        ExitAltScreenMsg,
+        BellMsg,
+        IdleMsg,
+        SetWindowTitleMsg,
+        SetCursorShapeMsg,
+    )
+}
+
+/// Writes one line per message received by [`crate::Program`].
+///
+/// Each line is `<sequence>\t<type name>`, where the sequence starts at 1.
+pub(crate) struct MessageLog {
+    writer: LineWriter<File>,
+    seq: u64,
+    names: HashMap<TypeId, &'static str>,
+}
+
+impl MessageLog {
+    /// Create (or truncate) the log file at `path`.
+    ///
+    /// `names` holds the message types registered with
+    /// [`crate::Program::with_message_type`], on top of the ones defined by `matcha`.
+    pub(crate) fn create(
+        path: &Path,
+        mut names: HashMap<TypeId, &'static str>,
+    ) -> std::io::Result<Self> {
+        names.extend(
+            framework_types()
+                .into_iter()
+                .map(|(id, name, _)| (id, name)),
+        );
+        Ok(Self {
+            writer: LineWriter::new(File::create(path)?),
+            seq: 0,
+            names,
+        })
+    }
+
+    /// Record a received message.
+    pub(crate) fn write(&mut self, msg: &Msg) -> std::io::Result<()> {
+        self.seq += 1;
+        let name = self.msg_type_name(msg);
+        writeln!(self.writer, "{}\t{}", self.seq, name)
+    }
+
+    /// Return a readable type name for a message.
+    ///
+    /// A [`Msg`] is a `dyn Any`, so the concrete type name is only known for registered
+    /// types. Other messages are reported by their `TypeId`.
+    fn msg_type_name(&self, msg: &Msg) -> String {
+        let msg: &dyn Any = &**msg;
+        match self.names.get(&msg.type_id()) {
+            Some(name) => name.to_string(),
+            None => format!("{} ({:?})", type_name_of_val(msg), msg.type_id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Names of the `*Msg` types declared in `src`, outside of test modules.
+    fn declared_msg_types() -> Vec<String> {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut names = vec![];
+        for entry in std::fs::read_dir(src).unwrap() {
+            let source = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            let source = source.split("#[cfg(test)]").next().unwrap();
+            for line in source.lines() {
+                let line = line.trim_start().trim_start_matches("pub(crate) ");
+                let line = line.trim_start_matches("pub ");
+                let Some(decl) = ["struct ", "enum ", "type "]
+                    .iter()
+                    .find_map(|keyword| line.strip_prefix(keyword))
+                else {
+                    continue;
+                };
+                let name: String = decl
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                if name.ends_with("Msg") && name != "Msg" {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    #[test]
+    fn every_framework_message_type_is_named() {
+        let registered: Vec<&str> = framework_types()
+            .into_iter()
+            .map(|(_, _, ident)| ident)
+            .collect();
+        let declared = declared_msg_types();
+        assert!(declared.iter().any(|name| name == "SequenceStepMsg"));
+        for name in declared {
+            assert!(
+                registered.contains(&name.as_str()),
+                "{name} is not registered in framework_types"
+            );
+        }
+    }
+}
@@ -5,6 +5,19 @@ pub fn quit() -> Msg {
     Box::new(QuitMsg)
 }
 
+/// Bell is a special command that rings the terminal bell.
+pub fn bell() -> Msg {
+    Box::new(BellMsg)
+}
+
+/// BellMsg in an internal message signals that the program should ring the
+/// terminal bell. You can send a BellMsg with Bell.
+pub struct BellMsg;
+
+/// IdleMsg is sent when no key or mouse input has arrived for the duration set with
+/// [`crate::Program::with_idle_timeout`].
+pub struct IdleMsg;
+
 /// quitMsg in an internal message signals that the program should quit. You can
 /// send a quitMsg with Quit.
 pub struct QuitMsg;
@@ -0,0 +1,40 @@
+use std::{
+    fs::File,
+    io::{LineWriter, Write},
+    path::Path,
+    time::Instant,
+};
+
+/// Writes frames rendered by [`crate::Program`] in the asciicast v2 format.
+///
+/// The first line is a JSON header with the terminal size. Each following line is an
+/// output event `[<seconds since start>, "o", "<frame>"]`. Every frame starts by clearing
+/// the screen, so the recording replays as a sequence of full frames.
+pub(crate) struct Recording {
+    writer: LineWriter<File>,
+    started_at: Instant,
+}
+
+impl Recording {
+    /// Create (or truncate) the recording file at `path` and write its header.
+    pub(crate) fn create(path: &Path, size: (u16, u16)) -> std::io::Result<Self> {
+        let mut writer = LineWriter::new(File::create(path)?);
+        let header = serde_json::json!({
+            "version": 2,
+            "width": size.0,
+            "height": size.1,
+        });
+        writeln!(writer, "{}", header)?;
+        Ok(Self {
+            writer,
+            started_at: Instant::now(),
+        })
+    }
+
+    /// Record a rendered frame.
+    pub(crate) fn frame(&mut self, frame: &str) -> std::io::Result<()> {
+        let elapsed = self.started_at.elapsed().as_secs_f64();
+        let event = serde_json::json!([elapsed, "o", format!("\x1b[H\x1b[2J{}", frame)]);
+        writeln!(self.writer, "{}", event)
+    }
+}
@@ -35,4 +35,40 @@ pub trait Termable {
     fn clear_current_line(&self) -> Result<(), std::io::Error>;
     /// Clear current line and move to previous line.
     fn clear_current_line_and_move_previous(&self) -> Result<(), std::io::Error>;
+    /// Set the window title.
+    fn set_window_title(&self, _title: &str) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+    /// Save the current window title on the terminal's title stack.
+    fn push_window_title(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+    /// Restore the window title saved by [`Termable::push_window_title`].
+    fn pop_window_title(&self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+    /// Set the shape of the hardware cursor.
+    fn set_cursor_shape(&self, _shape: CursorShape) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+}
+
+/// Shape of the hardware cursor, see [`Termable::set_cursor_shape`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CursorShape {
+    /// The shape configured by the user.
+    #[default]
+    Default,
+    /// A steady block (█).
+    Block,
+    /// A steady underline (_).
+    Underline,
+    /// A steady vertical bar (|).
+    Bar,
+    /// A blinking block.
+    BlinkingBlock,
+    /// A blinking underline.
+    BlinkingUnderline,
+    /// A blinking vertical bar.
+    BlinkingBar,
 }
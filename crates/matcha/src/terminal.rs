@@ -1,13 +1,15 @@
 use crossterm::{
-    cursor::{self, MoveTo, MoveToColumn},
+    cursor::{self, MoveTo, MoveToColumn, SetCursorStyle},
     execute,
     style::Print,
     terminal::{
         disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
-        LeaveAlternateScreen,
+        LeaveAlternateScreen, SetTitle,
     },
 };
 
+use crate::CursorShape;
+
 /// The default [`crate::Termable`] implementation backed by `crossterm`.
 pub struct DefaultTerminal;
 
@@ -83,4 +85,32 @@ impl crate::termable::Termable for DefaultTerminal {
             Clear(ClearType::CurrentLine)
         )
     }
+
+    fn set_window_title(&self, title: &str) -> Result<(), std::io::Error> {
+        // OSC 0: set icon name and window title.
+        execute!(std::io::stdout(), SetTitle(title))
+    }
+
+    fn push_window_title(&self) -> Result<(), std::io::Error> {
+        // XTWINOPS 22: push icon name and window title.
+        execute!(std::io::stdout(), Print("\x1b[22;0t"))
+    }
+
+    fn pop_window_title(&self) -> Result<(), std::io::Error> {
+        // XTWINOPS 23: pop icon name and window title.
+        execute!(std::io::stdout(), Print("\x1b[23;0t"))
+    }
+
+    fn set_cursor_shape(&self, shape: CursorShape) -> Result<(), std::io::Error> {
+        let style = match shape {
+            CursorShape::Default => SetCursorStyle::DefaultUserShape,
+            CursorShape::Block => SetCursorStyle::SteadyBlock,
+            CursorShape::Underline => SetCursorStyle::SteadyUnderScore,
+            CursorShape::Bar => SetCursorStyle::SteadyBar,
+            CursorShape::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            CursorShape::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            CursorShape::BlinkingBar => SetCursorStyle::BlinkingBar,
+        };
+        execute!(std::io::stdout(), style)
+    }
 }
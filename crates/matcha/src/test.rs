@@ -0,0 +1,233 @@
+//! Helpers for testing models without a terminal.
+//!
+//! [`Harness`] drives a [`Model`] the way [`crate::Program`] does, but leaves it to the
+//! test to decide when messages are delivered and commands are run.
+
+use std::collections::VecDeque;
+
+use crate::{
+    BatchMsg, BellMsg, Cmd, DelayMsg, ExecProcessMsg, Extensions, InitInput, Model, Msg,
+    OrderedBatchMsg, QuitMsg, ResizeEvent, SequenceMsg, SetCursorShapeMsg, SetWindowTitleMsg,
+    SyncCmd, TerminalSize,
+};
+
+/// Runs a model headlessly so tests can feed messages and inspect the view.
+///
+/// Commands returned by `init` and `update` are queued instead of being run right away.
+/// Call [`Harness::run_cmds`] to run them: sync commands are called directly and async
+/// commands go through [`Model::execute`] on a current-thread runtime, so a test always
+/// sees the same order of messages.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Display;
+/// use matcha::{test::Harness, InitInput, KeyCode, KeyEvent, KeyModifiers, Model, Msg, Cmd};
+///
+/// struct Echo(String);
+///
+/// impl Model for Echo {
+///     fn update(mut self, msg: &Msg) -> (Self, Option<Cmd>) {
+///         if let Some(KeyEvent { code: KeyCode::Char(c), .. }) = msg.downcast_ref::<KeyEvent>() {
+///             self.0.push(*c);
+///         }
+///         (self, None)
+///     }
+///
+///     fn view(&self) -> impl Display {
+///         self.0.clone()
+///     }
+/// }
+///
+/// let mut harness = Harness::new(Echo(String::new()), InitInput::default());
+/// harness.send(Box::new(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE)));
+/// harness.send(Box::new(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE)));
+/// assert_eq!(harness.view_string(), "hi");
+/// ```
+pub struct Harness<M: Model> {
+    model: Option<M>,
+    extensions: Extensions,
+    terminal_size: TerminalSize,
+    pending: VecDeque<Cmd>,
+    quit: bool,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl<M: Model> Harness<M> {
+    /// Create a harness and call [`Model::init`] with `input`.
+    pub fn new(model: M, input: InitInput) -> Self {
+        let (model, cmd) = model.init(&input);
+        Self {
+            model: Some(model),
+            extensions: input.extensions,
+            terminal_size: input.terminal_size,
+            pending: cmd.into_iter().collect(),
+            quit: false,
+            runtime: tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build a current-thread runtime"),
+        }
+    }
+
+    /// Deliver `msg` to the model and queue the command it returns.
+    ///
+    /// Internal messages are handled like [`crate::Program`] does: a quit message marks
+    /// the harness as quit, batches and sequences queue their commands, a
+    /// [`crate::delay`] queues its command without waiting, and bells,
+    /// window titles, cursor shapes or external processes are ignored. None of them reach
+    /// [`Model::update`]. A [`ResizeEvent`] also updates [`InitInput::terminal_size`].
+    pub fn send(&mut self, msg: Msg) {
+        if msg.is::<QuitMsg>() {
+            self.quit = true;
+            return;
+        }
+        let msg = match msg.downcast::<BatchMsg>() {
+            Ok(batch) => return self.pending.extend(*batch),
+            Err(msg) => msg,
+        };
+        let msg = match msg.downcast::<OrderedBatchMsg>() {
+            Ok(batch) => return self.pending.extend(batch.0),
+            Err(msg) => msg,
+        };
+        let msg = match msg.downcast::<SequenceMsg>() {
+            Ok(seq) => return self.pending.extend(seq.0),
+            Err(msg) => msg,
+        };
+        let msg = match msg.downcast::<DelayMsg>() {
+            Ok(delay) => return self.pending.push_back(delay.1),
+            Err(msg) => msg,
+        };
+        if msg.is::<BellMsg>()
+            || msg.is::<SetWindowTitleMsg>()
+            || msg.is::<SetCursorShapeMsg>()
+            || msg.is::<ExecProcessMsg>()
+        {
+            return;
+        }
+
+        if let Some(event) = msg.downcast_ref::<ResizeEvent>() {
+            self.terminal_size.set((event.0, event.1));
+        }
+
+        let model = self.model.take().expect("model is always present");
+        let (model, cmd) = model.update(&msg);
+        self.model = Some(model);
+        self.pending.extend(cmd);
+    }
+
+    /// Run the commands queued so far and deliver their messages.
+    ///
+    /// Commands queued while doing so are left for the next call, so timers that
+    /// reschedule themselves don't run forever. Returns how many commands were run.
+    pub fn run_cmds(&mut self) -> usize {
+        let count = self.pending.len();
+        for _ in 0..count {
+            match self.pending.pop_front() {
+                Some(Cmd::Sync(SyncCmd(cmd))) => self.send(cmd()),
+                Some(Cmd::Async(cmd)) => {
+                    let ext = self.extensions.clone();
+                    match self.runtime.block_on(M::execute(ext, cmd)) {
+                        Some(Cmd::Sync(SyncCmd(cmd))) => self.send(cmd()),
+                        Some(cmd) => self.pending.push_back(cmd),
+                        None => {}
+                    }
+                }
+                None => break,
+            }
+        }
+        count
+    }
+
+    /// Return the number of queued commands.
+    pub fn pending_cmds(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Return whether a quit message has been received.
+    pub fn is_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Return the current model.
+    pub fn model(&self) -> &M {
+        self.model.as_ref().expect("model is always present")
+    }
+
+    /// Render the current view.
+    pub fn view_string(&self) -> String {
+        self.model().view().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+    use crate::{batch, quit, r#async, sync, AsyncCmd};
+
+    struct Counter(u32);
+
+    struct Incr;
+
+    #[async_trait::async_trait]
+    impl Model for Counter {
+        fn init(self, _input: &InitInput) -> (Self, Option<Cmd>) {
+            (
+                self,
+                Some(batch(vec![sync!(Box::new(Incr)), r#async!(Box::new(Incr))])),
+            )
+        }
+
+        fn update(self, msg: &Msg) -> (Self, Option<Cmd>) {
+            if msg.is::<Incr>() {
+                let cmd = (self.0 == 1).then(|| Cmd::sync(Box::new(quit)));
+                return (Self(self.0 + 1), cmd);
+            }
+            (self, None)
+        }
+
+        async fn execute(_ext: Extensions, AsyncCmd(cmd): AsyncCmd) -> Option<Cmd> {
+            let msg = cmd();
+            Some(Cmd::sync(Box::new(move || msg)))
+        }
+
+        fn view(&self) -> impl Display {
+            self.0
+        }
+    }
+
+    #[test]
+    fn runs_queued_commands_one_round_at_a_time() {
+        let mut harness = Harness::new(Counter(0), InitInput::default());
+        assert_eq!(harness.pending_cmds(), 1);
+
+        // The batch queues a sync and an async command.
+        assert_eq!(harness.run_cmds(), 1);
+        assert_eq!(harness.pending_cmds(), 2);
+
+        assert_eq!(harness.run_cmds(), 2);
+        assert_eq!(harness.view_string(), "2");
+        assert!(!harness.is_quit());
+
+        harness.run_cmds();
+        assert!(harness.is_quit());
+    }
+
+    #[test]
+    fn delays_are_queued_without_waiting() {
+        let mut harness = Harness::new(Counter(0), InitInput::default());
+        let Cmd::Sync(SyncCmd(delay)) =
+            crate::delay(std::time::Duration::from_secs(60), || Box::new(Incr))
+        else {
+            unreachable!("delay is a sync command");
+        };
+        harness.send(delay());
+        assert_eq!(harness.pending_cmds(), 2);
+
+        // The init batch is expanded and the delayed message is delivered right away.
+        harness.run_cmds();
+        assert_eq!(harness.view_string(), "1");
+    }
+}